@@ -23,12 +23,38 @@ pub(crate) fn struct_value_type(name: &str) -> Option<Ident> {
         "BaselineCalibrationParam" => {
             Some(Ident::new("OBBaselineCalibrationParam", Span::call_site()))
         }
-        "ColorAeRoi" | "DepthAeRoi" => Some(Ident::new("OBRegionOfInterest", Span::call_site())),
-        "DeviceSerialNumber" => Some(Ident::new("OBDeviceSerialNumber", Span::call_site())),
+        "ColorAeRoi" | "DepthAeRoi" | "RgbCropRoi" => {
+            Some(Ident::new("OBRegionOfInterest", Span::call_site()))
+        }
+        "AsicSerialNumber" | "DeviceSerialNumber" => {
+            Some(Ident::new("OBDeviceSerialNumber", Span::call_site()))
+        }
         "DeviceTemperature" => Some(Ident::new("OBDeviceTemperature", Span::call_site())),
         "DispOffsetConfig" => Some(Ident::new("OBDispOffsetConfig", Span::call_site())),
         "MultiDeviceSyncConfig" => Some(Ident::new("OBMultiDeviceSyncConfig", Span::call_site())),
         "PresetResolutionConfig" => Some(Ident::new("OBPresetResolutionConfig", Span::call_site())),
+        "DepthHdrConfig" => Some(Ident::new("OBHdrConfig", Span::call_site())),
+        "DeviceIpAddrConfig" | "DeviceStaticIpConfigRecord" => {
+            Some(Ident::new("OBNetIpConfig", Span::call_site()))
+        }
+        "DeviceIpAddrConfigV2" => Some(Ident::new("OBNetIpConfigV2", Span::call_site())),
+        "TofExposureThresholdControl" => Some(Ident::new(
+            "OBTofExposureThresholdControl",
+            Span::call_site(),
+        )),
+        // Left untyped on purpose:
+        // - ColorSyncedExposureParam has no struct definition in the SDK headers.
+        // - CurrentDepthAlgMode is not documented to use a struct of the SDK headers,
+        //   and a wrong guess panics on the size check of the typed accessors.
+        //   Use `Device::current_depth_work_mode` instead.
+        _ => None,
+    }
+}
+
+/// Element type of struct properties that are read as a variable-length array
+pub(crate) fn raw_data_element_type(name: &str) -> Option<Ident> {
+    match name {
+        "DepthPrecisionSupportList" => Some(Ident::new("u16", Span::call_site())),
         _ => None,
     }
 }
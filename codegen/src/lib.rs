@@ -5,7 +5,7 @@ use convert_case::Casing;
 use proc_macro2::{Ident, Span};
 use regex::Regex;
 
-use crate::custom::{VARIANT_RENAMES, struct_value_type};
+use crate::custom::{VARIANT_RENAMES, raw_data_element_type, struct_value_type};
 use crate::helpers::{compute_trimmed_names, doc_strings, pretty_print_file, rustfmt_in_place};
 
 mod custom;
@@ -132,12 +132,18 @@ fn generate_property_id_types_file(
     tokens.extend(quote::quote! {
         use super::structs::OBDeviceTime;
         use crate::sys::orb::{
-            OBBaselineCalibrationParam, OBDeviceSerialNumber, OBDeviceTemperature,
-            OBDispOffsetConfig, OBMultiDeviceSyncConfig, OBPresetResolutionConfig,
-            OBRegionOfInterest,
+            OBBaselineCalibrationParam, OBDeviceSerialNumber,
+            OBDeviceTemperature, OBDispOffsetConfig, OBHdrConfig, OBMultiDeviceSyncConfig,
+            OBNetIpConfig, OBNetIpConfigV2, OBPresetResolutionConfig, OBPropertyID,
+            OBPropertyType, OBRegionOfInterest, OBTofExposureThresholdControl,
         };
     });
 
+    // Name, ID and value type of every scalar property, used for string-keyed access
+    let mut table_entries = Vec::new();
+    // Name, ID and value size of every struct property with a known value type
+    let mut struct_table_entries = Vec::new();
+
     let mut property_id_types = property_id_types.iter().collect::<Vec<_>>();
     property_id_types.sort_by_key(|(name, _)| *name);
 
@@ -147,6 +153,18 @@ fn generate_property_id_types_file(
             .get(name)
             .map(|s| s.as_str())
             .unwrap_or(NO_DOC_COMMENT);
+        let value_type = match prop_type {
+            PropertyIDType::Bool => Some(Ident::new("BoolProperty", Span::call_site())),
+            PropertyIDType::Int => Some(Ident::new("IntProperty", Span::call_site())),
+            PropertyIDType::Float => Some(Ident::new("FloatProperty", Span::call_site())),
+            _ => None,
+        };
+        if let Some(value_type) = value_type {
+            table_entries.push(quote::quote! {
+                (#name, OBPropertyID::#name_token, OBPropertyType::#value_type)
+            });
+        }
+
         let prop_tokens = match prop_type {
             PropertyIDType::Bool => quote::quote! {
                 define_bool_property!(#name_token, #doc_comment);
@@ -160,9 +178,16 @@ fn generate_property_id_types_file(
             PropertyIDType::Struct => {
                 let ty = struct_value_type(name.as_str());
                 if let Some(ty) = ty {
+                    struct_table_entries.push(quote::quote! {
+                        (#name, OBPropertyID::#name_token, size_of::<#ty>())
+                    });
                     quote::quote! {
                         define_struct_property!(#name_token, #ty, #doc_comment);
                     }
+                } else if let Some(element) = raw_data_element_type(name.as_str()) {
+                    quote::quote! {
+                        define_raw_data_property!(#name_token, #element, #doc_comment);
+                    }
                 } else {
                     // If we don't have a struct value type, we can't generate the property
                     tokens.extend(quote::quote! {
@@ -177,6 +202,19 @@ fn generate_property_id_types_file(
 
         tokens.extend(prop_tokens);
     }
+
+    tokens.extend(quote::quote! {
+        /// Name, ID and value type of every bool, int and float property, sorted by name
+        pub(crate) const PROPERTY_TABLE: &[(&str, OBPropertyID, OBPropertyType)] = &[
+            #(#table_entries),*
+        ];
+
+        /// Name, ID and value size of every struct property with a known value type, sorted by name
+        pub(crate) const STRUCT_PROPERTY_TABLE: &[(&str, OBPropertyID, usize)] = &[
+            #(#struct_table_entries),*
+        ];
+    });
+
     tokens
 }
 
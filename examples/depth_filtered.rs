@@ -2,14 +2,10 @@ use std::time::Duration;
 
 use clap::Parser;
 use orbbec_sdk::{
-    Context, Format, HoleFillMode, LogSeverity, PermissionType, SensorType,
-    filter::{
-        DecimationFilter, Filter, HoleFillingFilter, SpatialModerateFilter, TemporalFilter,
-        ThresholdFilter,
-    },
+    Context, Format, LogSeverity, SensorType,
+    filter::FilterControl,
     logger::Logger,
     pipeline::{Config, Pipeline},
-    prop,
 };
 
 const DEPTH_WIDTH: u16 = 848;
@@ -29,8 +25,13 @@ struct Args {
     #[arg(long, default_value_t = FPS)]
     fps: u8,
 
-    #[arg(long, default_value_t = 2)]
-    decimation_factor: u8,
+    /// Enable a recommended filter that is disabled by default (e.g. DecimationFilter)
+    #[arg(long)]
+    enable: Vec<String>,
+
+    /// Disable a recommended filter (e.g. TemporalFilter)
+    #[arg(long)]
+    disable: Vec<String>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -53,19 +54,19 @@ fn main() -> anyhow::Result<()> {
         eprintln!("Failed to load preset (may not be supported on this device): {err}");
     }
 
-    // Enable depth noise filter
-    if device.is_property_supported::<prop::HwNoiseRemoveFilterEnable>(PermissionType::Write)? {
-        // HW filter is supported, use it instead of SW filter
-        device.set_property::<prop::HwNoiseRemoveFilterEnable>(true)?;
-        device.set_property::<prop::HwNoiseRemoveFilterThreshold>(0.2)?;
-        device.set_property::<prop::DepthNoiseRemovalFilter>(false)?;
-        println!("Using HW depth noise filter.");
-    } else {
-        // HW filter not supported, use SW filter
-        device.set_property::<prop::DepthNoiseRemovalFilter>(true)?;
-        device.set_property::<prop::DepthNoiseRemovalFilterMaxDiff>(256)?;
-        device.set_property::<prop::DepthNoiseRemovalFilterMaxSpeckleSize>(80)?;
-        println!("Using SW depth noise filter.");
+    // Get the filters recommended for the device, with tuned parameters
+    let mut filters = device.recommended_depth_filters()?;
+    for name in &args.enable {
+        filters.set_enabled(name, true)?;
+    }
+    for name in &args.disable {
+        filters.set_enabled(name, false)?;
+    }
+
+    println!("Depth filters:");
+    for filter in &filters {
+        let state = if filter.is_enabled()? { "on" } else { "off" };
+        println!("  [{state}] {}", filter.name());
     }
 
     // Create pipeline
@@ -84,30 +85,6 @@ fn main() -> anyhow::Result<()> {
     // Enable depth stream
     config.enable_stream_with_profile(&depth_profile)?;
 
-    // Create decimation filter
-    let mut decimation_filter: DecimationFilter = DecimationFilter::new()?;
-    decimation_filter.set_factor(args.decimation_factor)?;
-
-    // Create spatial filter (Moderate variant)
-    let mut spatial_filter = SpatialModerateFilter::new()?;
-    spatial_filter.set_threshold(160)?;
-    spatial_filter.set_magnitude(3)?;
-    spatial_filter.set_radius(5)?;
-
-    // Create temporal filter
-    let mut temporal_filter = TemporalFilter::new()?;
-    temporal_filter.set_threshold(0.1)?;
-    temporal_filter.set_weight(0.4)?;
-
-    // Hole filling filter
-    let mut hole_filling_filter = HoleFillingFilter::new()?;
-    hole_filling_filter.set_mode(HoleFillMode::Farthest)?;
-
-    // Threshold filter
-    let mut threshold_filter = ThresholdFilter::new()?;
-    threshold_filter.set_min_depth(200)?;
-    threshold_filter.set_max_depth(4000)?;
-
     // Start streaming
     pipeline.start(&config)?;
 
@@ -135,17 +112,6 @@ fn main() -> anyhow::Result<()> {
         ),
     )?;
 
-    rr.log_static("orbbec/camera/depth_filtered", &{
-        // Scale down the intrinsic by the decimation factor
-        let f = args.decimation_factor as f32;
-        rerun::Pinhole::new(
-            rerun::components::PinholeProjection::from_focal_length_and_principal_point(
-                [intrinsic.fx / f, intrinsic.fy / f],
-                [intrinsic.cx / f, intrinsic.cy / f],
-            ),
-        )
-    })?;
-
     loop {
         // Get frameset
         let frameset = match pipeline.wait_for_frames(Duration::from_millis(100))? {
@@ -162,30 +128,36 @@ fn main() -> anyhow::Result<()> {
             continue;
         };
 
-        let orig_depth_data = depth_frame.raw_data();
         let orig_width = depth_frame.width() as u32;
         let orig_height = depth_frame.height() as u32;
-        let orig_depth_scale = depth_frame.depth_scale();
-        let timestamp_us = depth_frame.timestamp_us();
-
-        // Apply filters
-        let depth_frame = decimation_filter.process(&depth_frame)?;
-        let depth_frame = spatial_filter.process(&depth_frame)?;
-        let depth_frame = temporal_filter.process(&depth_frame)?;
-        let depth_frame = hole_filling_filter.process(&depth_frame)?;
-        let depth_frame = threshold_filter.process(&depth_frame)?;
-
-        let depth_filtered_data = depth_frame.raw_data();
 
         rr.set_time(
             "camera_clock",
-            std::time::Duration::from_micros(timestamp_us),
+            std::time::Duration::from_micros(depth_frame.timestamp_us()),
         );
 
+        // Log the original frame before the filter chain takes it
         rr.log(
             "orbbec/camera/depth",
-            &rerun::DepthImage::from_gray16(orig_depth_data, [orig_width, orig_height])
-                .with_meter(1000.0 / orig_depth_scale),
+            &rerun::DepthImage::from_gray16(depth_frame.raw_data(), [orig_width, orig_height])
+                .with_meter(1000.0 / depth_frame.depth_scale()),
+        )?;
+
+        // Apply filters
+        let depth_frame = filters.process(depth_frame)?;
+
+        let depth_filtered_data = depth_frame.raw_data();
+
+        // Scale down the intrinsic if the decimation filter is enabled
+        let f = orig_width as f32 / depth_frame.width() as f32;
+        rr.log(
+            "orbbec/camera/depth_filtered",
+            &rerun::Pinhole::new(
+                rerun::components::PinholeProjection::from_focal_length_and_principal_point(
+                    [intrinsic.fx / f, intrinsic.fy / f],
+                    [intrinsic.cx / f, intrinsic.cy / f],
+                ),
+            ),
         )?;
 
         rr.log(
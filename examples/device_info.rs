@@ -13,7 +13,16 @@ fn main() -> anyhow::Result<()> {
         let device_info = device.info()?;
 
         print!("Device {i}: ");
-        println!("{device_info:#?}\n");
+        println!("{device_info:#?}");
+
+        println!("Supported properties:");
+        for property in device.supported_properties()? {
+            println!(
+                "  {} ({:?}, {:?})",
+                property.name, property.property_type, property.permission
+            );
+        }
+        println!();
     }
 
     Ok(())
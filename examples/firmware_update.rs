@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::Parser;
+use orbbec_sdk::{Context, LogSeverity, firmware::FirmwareVersion, logger::Logger};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, default_value_t = 0)]
+    device_index: usize,
+
+    /// Only compare the image version against the device firmware
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Reboot the device after the update and wait for it to reconnect
+    #[arg(long)]
+    reboot: bool,
+
+    /// Firmware image file
+    image: PathBuf,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    // Don't create a ./Log directory in the current working directory
+    Logger::set_directory(LogSeverity::Off, None)?;
+
+    // Create context and get device list
+    let context = Context::new()?;
+    let devices = context.query_device_list()?;
+
+    if devices.is_empty() {
+        anyhow::bail!("no Orbbec devices found");
+    }
+    let mut device = devices.get(args.device_index)?;
+
+    // The image version is taken from the file name (e.g. Gemini330_Firmware_v1.4.60.bin)
+    if let Some(image_version) = FirmwareVersion::from_file_name(&args.image) {
+        let check = device.check_firmware_version(&image_version)?;
+        println!(
+            "Device firmware: {} | image: {}",
+            check.device_version, check.image_version
+        );
+
+        if check.is_up_to_date() {
+            println!("Device is already up to date");
+            return Ok(());
+        }
+    } else {
+        println!("Unable to find the image version in the file name");
+    }
+
+    if args.dry_run {
+        return Ok(());
+    }
+
+    let state = device.update_firmware(args.image.as_path(), |state, message, percent| {
+        println!("[{percent:3}%] {state:?} {message}");
+    })?;
+    println!("Update finished with {state:?}");
+
+    if !args.reboot {
+        println!("Reboot the device to apply the update");
+        return Ok(());
+    }
+
+    let serial_number = device.info()?.serial_number();
+    device.reboot()?;
+
+    let device = context.wait_for_device(&serial_number, Duration::from_secs(60))?;
+    println!(
+        "Device reconnected with firmware {}",
+        device.info()?.firmware_version()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use clap::Parser;
+use orbbec_sdk::{
+    Context, Format, HdrConfig, LogSeverity, SensorType,
+    filter::{Filter, HdrMergeFilter, SequenceIdFilter},
+    frame::DepthFrame,
+    logger::Logger,
+    pipeline::{Config, Pipeline},
+    prop,
+};
+
+const DEPTH_WIDTH: u16 = 848;
+const DEPTH_HEIGHT: u16 = 480;
+const FPS: u8 = 30;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, default_value_t = 0)]
+    device_index: usize,
+
+    #[arg(long, default_value_t = DEPTH_WIDTH)]
+    depth_width: u16,
+    #[arg(long, default_value_t = DEPTH_HEIGHT)]
+    depth_height: u16,
+
+    #[arg(long, default_value_t = FPS)]
+    fps: u8,
+
+    /// Exposure (in microseconds) used for dark surfaces
+    #[arg(long, default_value_t = 7500)]
+    long_exposure: u32,
+    /// Exposure (in microseconds) used for reflective surfaces
+    #[arg(long, default_value_t = 100)]
+    short_exposure: u32,
+    #[arg(long, default_value_t = 16)]
+    gain: u32,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    // Don't create a ./Log directory in the current working directory
+    Logger::set_directory(LogSeverity::Off, None)?;
+
+    // Create context and get device list
+    let context = Context::new()?;
+    let devices = context.query_device_list()?;
+
+    if devices.is_empty() {
+        anyhow::bail!("no Orbbec devices found");
+    }
+    let mut device = devices.get(args.device_index)?;
+
+    // Alternate between a long and a short exposure, frame by frame
+    let hdr_config = HdrConfig {
+        enable: 1,
+        sequence_name: 0,
+        exposure_1: args.long_exposure,
+        gain_1: args.gain,
+        exposure_2: args.short_exposure,
+        gain_2: args.gain,
+    };
+    device.set_property::<prop::DepthHdrConfig>(hdr_config)?;
+
+    // Create pipeline
+    let mut config = Config::new()?;
+    let mut pipeline = Pipeline::new(&device)?;
+
+    // Get depth stream profile
+    let depth_profiles = pipeline.get_stream_profiles(SensorType::Depth)?;
+    let depth_profile = depth_profiles.get_video_stream_profile(
+        args.depth_width,
+        args.depth_height,
+        Format::Y16,
+        args.fps,
+    )?;
+
+    // Enable depth stream
+    config.enable_stream_with_profile(&depth_profile)?;
+
+    // Merge both exposures into a single frame
+    let hdr_merge_filter = HdrMergeFilter::new()?;
+
+    // Split the stream by exposure, to compare against the merged frame
+    let mut long_exposure_filter = SequenceIdFilter::new()?;
+    long_exposure_filter.set_sequence_id(0)?;
+    let mut short_exposure_filter = SequenceIdFilter::new()?;
+    short_exposure_filter.set_sequence_id(1)?;
+
+    // Start streaming
+    pipeline.start(&config)?;
+
+    let rr = rerun::RecordingStreamBuilder::new("orbbec_sdk_rs_hdr_merge").connect_grpc()?;
+
+    // Make camera point forward instead of up
+    rr.log_static(
+        "orbbec",
+        &rerun::Transform3D::from_rotation(rerun::Rotation3D::AxisAngle(
+            rerun::components::RotationAxisAngle::new(
+                rerun::Vec3D::new(1.0, 0.0, 0.0),
+                -90_f32.to_radians(),
+            ),
+        )),
+    )?;
+
+    let intrinsic = depth_profile.get_intrinsic()?;
+    for entity in ["long_exposure", "short_exposure", "merged"] {
+        rr.log_static(
+            format!("orbbec/camera/{entity}"),
+            &rerun::Pinhole::new(
+                rerun::components::PinholeProjection::from_focal_length_and_principal_point(
+                    [intrinsic.fx, intrinsic.fy],
+                    [intrinsic.cx, intrinsic.cy],
+                ),
+            ),
+        )?;
+    }
+
+    let log_depth = |entity: &str, depth_frame: &DepthFrame| -> anyhow::Result<()> {
+        rr.set_time(
+            "camera_clock",
+            std::time::Duration::from_micros(depth_frame.timestamp_us()),
+        );
+
+        rr.log(
+            format!("orbbec/camera/{entity}"),
+            &rerun::DepthImage::from_gray16(
+                depth_frame.raw_data(),
+                [depth_frame.width() as u32, depth_frame.height() as u32],
+            )
+            .with_meter(1000.0 / depth_frame.depth_scale()),
+        )?;
+
+        Ok(())
+    };
+
+    loop {
+        // Get frameset
+        let frameset = match pipeline.wait_for_frames(Duration::from_millis(100))? {
+            Some(frameset) => frameset,
+            None => {
+                eprintln!("Timeout waiting for frames.");
+                continue;
+            }
+        };
+
+        // Frames of the other sequence are dropped, so the result may have no depth frame
+        let split = [
+            ("long_exposure", &long_exposure_filter),
+            ("short_exposure", &short_exposure_filter),
+        ];
+        for (entity, filter) in split {
+            let depth_frame = filter.process(&frameset)?.get_depth_frame().ok().flatten();
+            if let Some(depth_frame) = depth_frame {
+                log_depth(entity, &depth_frame)?;
+            }
+        }
+
+        let merged = hdr_merge_filter.process(&frameset)?;
+        if let Some(depth_frame) = merged.get_depth_frame()? {
+            log_depth("merged", &depth_frame)?;
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use clap::Parser;
+use orbbec_sdk::{
+    AccelFullScaleRange, AccelSampleRate, Context, GyroFullScaleRange, GyroSampleRate, LogSeverity,
+    logger::Logger,
+    pipeline::{Config, Pipeline},
+};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, default_value_t = 0)]
+    device_index: usize,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    // Don't create a ./Log directory in the current working directory
+    Logger::set_directory(LogSeverity::Off, None)?;
+
+    // Create context and get device list
+    let context = Context::new()?;
+    let devices = context.query_device_list()?;
+
+    if devices.is_empty() {
+        anyhow::bail!("no Orbbec devices found");
+    }
+    let device = devices.get(args.device_index)?;
+
+    // Create pipeline
+    let mut config = Config::new()?;
+    let mut pipeline = Pipeline::new(&device)?;
+
+    // Enable accelerometer and gyroscope streams
+    config.enable_accel(AccelFullScaleRange::Fs4G, AccelSampleRate::Rate200Hz)?;
+    config.enable_gyro(GyroFullScaleRange::Fs1000Dps, GyroSampleRate::Rate200Hz)?;
+
+    // Start streaming
+    pipeline.start(&config)?;
+
+    loop {
+        // Get frameset
+        let frameset = match pipeline.wait_for_frames(Duration::from_millis(100))? {
+            Some(frameset) => frameset,
+            None => {
+                eprintln!("Timeout waiting for frames.");
+                continue;
+            }
+        };
+
+        if let Some(accel_frame) = frameset.get_accel_frame()? {
+            let accel = accel_frame.value();
+
+            // With the camera at rest, the accelerometer only measures gravity
+            let norm = (accel.x * accel.x + accel.y * accel.y + accel.z * accel.z).sqrt();
+            let pitch = (-accel.z / norm).asin().to_degrees();
+            let roll = accel.x.atan2(-accel.y).to_degrees();
+
+            println!(
+                "[{}] accel: ({:.3}, {:.3}, {:.3}) | pitch: {pitch:.1}° roll: {roll:.1}° | temp: {:.1}°C",
+                accel_frame.timestamp_us(),
+                accel.x,
+                accel.y,
+                accel.z,
+                accel_frame.temperature()
+            );
+        }
+
+        if let Some(gyro_frame) = frameset.get_gyro_frame()? {
+            let gyro = gyro_frame.value();
+            println!(
+                "[{}] gyro: ({:.3}, {:.3}, {:.3}) | temp: {:.1}°C",
+                gyro_frame.timestamp_us(),
+                gyro.x,
+                gyro.y,
+                gyro.z,
+                gyro_frame.temperature()
+            );
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use clap::Parser;
+use orbbec_sdk::{Context, LogSeverity, MetadataType, SensorType, frame::IrFrame, logger::Logger};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, default_value_t = 0)]
+    device_index: usize,
+
+    #[arg(long, default_value_t = 5)]
+    seconds: u64,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    // Don't create a ./Log directory in the current working directory
+    Logger::set_directory(LogSeverity::Off, None)?;
+
+    // Create context and get device list
+    let context = Context::new()?;
+    let devices = context.query_device_list()?;
+
+    if devices.is_empty() {
+        anyhow::bail!("no Orbbec devices found");
+    }
+    let device = devices.get(args.device_index)?;
+
+    // Stereo cameras expose left/right IR sensors, ToF cameras a single IR sensor
+    let sensors = device.sensors()?;
+    let mut sensor = match sensors.get_by_type(SensorType::IrLeft)? {
+        Some(sensor) => sensor,
+        None => sensors
+            .get_by_type(SensorType::Ir)?
+            .ok_or_else(|| anyhow::anyhow!("device has no IR sensor"))?,
+    };
+
+    // Pick the profile with the highest frame rate
+    let profiles = sensor.get_stream_profiles()?;
+    let profile = profiles
+        .iter()
+        .filter_map(Result::ok)
+        .max_by_key(|p| p.fps())
+        .ok_or_else(|| anyhow::anyhow!("IR sensor has no stream profiles"))?;
+
+    println!(
+        "Streaming {:?} at {}x{} @ {}fps",
+        sensor.sensor_type()?,
+        profile.width(),
+        profile.height(),
+        profile.fps()
+    );
+
+    // Start the sensor directly, without the pipeline frame aggregation
+    sensor.start(&profile, |frame: IrFrame| {
+        // Not every device reports every metadata value
+        let exposure = frame.metadata(MetadataType::Exposure).ok().flatten();
+        let laser = frame.metadata(MetadataType::LaserStatus).ok().flatten();
+
+        println!(
+            "[{}] IR frame {}x{} ({:?}) | exposure: {exposure:?} | laser: {laser:?}",
+            frame.timestamp_us(),
+            frame.width(),
+            frame.height(),
+            frame.format()
+        );
+    })?;
+
+    std::thread::sleep(Duration::from_secs(args.seconds));
+    sensor.stop()?;
+
+    Ok(())
+}
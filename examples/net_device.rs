@@ -0,0 +1,56 @@
+use std::net::Ipv4Addr;
+
+use clap::Parser;
+use orbbec_sdk::{Context, LogSeverity, logger::Logger};
+
+#[derive(Parser, Debug)]
+struct Args {
+    /// Open the device at this IP address instead of enumerating
+    #[arg(long)]
+    ip: Option<Ipv4Addr>,
+
+    #[arg(long, default_value_t = 8090)]
+    port: u16,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    // Don't create a ./Log directory in the current working directory
+    Logger::set_directory(LogSeverity::Off, None)?;
+
+    let context = Context::new()?;
+
+    if let Some(ip) = args.ip {
+        // Connect directly, without GVCP discovery
+        let device = context.open_net_device(ip, args.port)?;
+        println!("{:#?}", device.info()?);
+        return Ok(());
+    }
+
+    // Discover network devices alongside USB ones
+    context.enable_net_device_enumeration(true)?;
+    let devices = context.query_device_list()?;
+
+    for i in 0..devices.len() {
+        println!(
+            "Device {i}: {} ({:?})",
+            devices.ip_address(i)?,
+            devices.ip_source_type(i)?
+        );
+        println!(
+            "  mask: {} | gateway: {}",
+            devices.subnet_mask(i)?,
+            devices.gateway(i)?
+        );
+        println!(
+            "  host interface: {} ({} / {}, MAC {})",
+            devices.local_interface_name(i)?,
+            devices.local_ip(i)?,
+            devices.local_subnet_length(i)?,
+            devices.local_mac(i)?
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use clap::Parser;
+use orbbec_sdk::{Context, LogSeverity, logger::Logger, prop::PropertyValue};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, default_value_t = 0)]
+    device_index: usize,
+
+    /// Property to read or write (e.g. ColorExposure)
+    name: String,
+
+    /// Value to write (true/false, integer or float); the property is only read if omitted
+    value: Option<String>,
+}
+
+fn parse_value(value: &str) -> anyhow::Result<PropertyValue> {
+    if let Ok(v) = value.parse::<bool>() {
+        return Ok(PropertyValue::Bool(v));
+    }
+    if let Ok(v) = value.parse::<i32>() {
+        return Ok(PropertyValue::Int(v));
+    }
+    if let Ok(v) = value.parse::<f32>() {
+        return Ok(PropertyValue::Float(v));
+    }
+    anyhow::bail!("invalid property value: {value}")
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    // Don't create a ./Log directory in the current working directory
+    Logger::set_directory(LogSeverity::Off, None)?;
+
+    // Create context and get device list
+    let context = Context::new()?;
+    let devices = context.query_device_list()?;
+
+    if devices.is_empty() {
+        anyhow::bail!("no Orbbec devices found");
+    }
+    let mut device = devices.get(args.device_index)?;
+
+    if let Some(value) = &args.value {
+        device.set_property_by_name(&args.name, parse_value(value)?)?;
+    }
+
+    let value = device.get_property_by_name(&args.name)?;
+    println!("{}: {value:?}", args.name);
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 //! Helpers for interpreting Orbbec calibration parameters.
-use crate::sys::orb::{OBCameraDistortion, OBCameraDistortionModel, OBCameraIntrinsic};
+use crate::sys::orb::{
+    OBAccelIntrinsic, OBCameraDistortion, OBCameraDistortionModel, OBCameraIntrinsic,
+    OBGyroIntrinsic,
+};
 
 #[cfg(feature = "nalgebra")]
 use nalgebra::{Matrix2, Matrix3, Vector2};
@@ -102,6 +105,69 @@ impl From<CameraDistortion> for OBCameraDistortion {
     }
 }
 
+/// Accelerometer intrinsic parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccelIntrinsic {
+    /// In-run bias instability.
+    pub noise_density: f64,
+    /// Random walk.
+    pub random_walk: f64,
+    /// Reference temperature.
+    pub reference_temp: f64,
+    /// Bias for x, y, z axis.
+    pub bias: [f64; 3],
+    /// Gravity direction for x, y, z axis.
+    pub gravity: [f64; 3],
+    /// Scale factor and three-axis non-orthogonal error (row-major 3x3).
+    pub scale_misalignment: [f64; 9],
+    /// Linear temperature drift coefficient (row-major 3x3).
+    pub temp_slope: [f64; 9],
+}
+
+impl From<OBAccelIntrinsic> for AccelIntrinsic {
+    fn from(ob: OBAccelIntrinsic) -> Self {
+        Self {
+            noise_density: { ob.noise_density },
+            random_walk: { ob.random_walk },
+            reference_temp: { ob.reference_temp },
+            bias: { ob.bias },
+            gravity: { ob.gravity },
+            scale_misalignment: { ob.scale_misalignment },
+            temp_slope: { ob.temp_slope },
+        }
+    }
+}
+
+/// Gyroscope intrinsic parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GyroIntrinsic {
+    /// In-run bias instability.
+    pub noise_density: f64,
+    /// Random walk.
+    pub random_walk: f64,
+    /// Reference temperature.
+    pub reference_temp: f64,
+    /// Bias for x, y, z axis.
+    pub bias: [f64; 3],
+    /// Scale factor and three-axis non-orthogonal error (row-major 3x3).
+    pub scale_misalignment: [f64; 9],
+    /// Linear temperature drift coefficient (row-major 3x3).
+    pub temp_slope: [f64; 9],
+}
+
+impl From<OBGyroIntrinsic> for GyroIntrinsic {
+    fn from(ob: OBGyroIntrinsic) -> Self {
+        Self {
+            noise_density: { ob.noise_density },
+            random_walk: { ob.random_walk },
+            reference_temp: { ob.reference_temp },
+            bias: { ob.bias },
+            scale_misalignment: { ob.scale_misalignment },
+            temp_slope: { ob.temp_slope },
+        }
+    }
+}
+
 impl CameraIntrinsic {
     /// Create a new camera intrinsic.
     /// ### Arguments
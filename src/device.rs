@@ -1,9 +1,29 @@
 //! Device module
+use std::ffi::{CStr, c_void};
 use std::fmt;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::error::{OrbbecError, OrbbecErrorData};
-use crate::sys::prop::{GetProperty, Property, SetProperty};
-use crate::{Context, DeviceType, PermissionType, sys};
+use crate::error::{OrbbecError, OrbbecErrorData, path_to_cstring, to_cstring};
+use crate::filter::FilterChain;
+use crate::firmware::{FirmwareCheck, FirmwareSource, FirmwareVersion, check_update_state};
+use crate::sensor::SensorList;
+use crate::snapshot::{PropertySnapshot, SnapshotValue, write_order};
+use crate::sync::{MultiDeviceSyncConfig, sync_modes_from_bitmap};
+use crate::sys::device::{fw_update_trampoline, state_changed_noop, state_changed_trampoline};
+use crate::sys::orb::{OBDepthWorkMode, OBErrorCode};
+use crate::sys::prop::{
+    GetProperty, Property, PropertyItem, PropertyRange, PropertyValue, RangedProperty, SetProperty,
+    find_property, find_struct_property, scalar_property_name, struct_property_name,
+};
+use crate::{
+    Context, DepthWorkModeTag, DeviceState, DeviceType, IpSourceType, MultiDeviceSyncMode,
+    PermissionType, PropertyId, PropertyType, SensorType, UpdateState, sys,
+};
+
+/// Maximum size (in bytes) of the customer data stored on a device
+// Ref: https://github.com/orbbec/OrbbecSDK_v2/blob/815ae047cc977a1f7edd2b97b69ff6cd29f510b3/include/libobsensor/h/Device.h (ob_device_write_customer_data)
+pub const MAX_CUSTOMER_DATA_SIZE: usize = 65532;
 
 /// Device information
 pub struct DeviceInfo {
@@ -91,6 +111,36 @@ impl DeviceInfo {
         cstr.to_string_lossy().into_owned()
     }
 
+    /// Get the device IP address ("0.0.0.0" for non-network devices)
+    pub fn ip_address(&self) -> String {
+        // Unwrap is safe here because internal pointer is guaranteed to be valid
+        // SDK only returns error for this function if pointer is NULL
+        // Ref: https://github.com/orbbec/OrbbecSDK_v2/blob/815ae047cc977a1f7edd2b97b69ff6cd29f510b3/src/impl/Device.cpp (ob_device_info_get_ip_address)
+        let cstr = self.inner.get_ip_address().unwrap();
+
+        cstr.to_string_lossy().into_owned()
+    }
+
+    /// Get the device subnet mask ("0.0.0.0" for non-network devices)
+    pub fn subnet_mask(&self) -> String {
+        // Unwrap is safe here because internal pointer is guaranteed to be valid
+        // SDK only returns error for this function if pointer is NULL
+        // Ref: https://github.com/orbbec/OrbbecSDK_v2/blob/815ae047cc977a1f7edd2b97b69ff6cd29f510b3/src/impl/Device.cpp (ob_device_info_get_subnet_mask)
+        let cstr = self.inner.get_subnet_mask().unwrap();
+
+        cstr.to_string_lossy().into_owned()
+    }
+
+    /// Get the device gateway ("0.0.0.0" for non-network devices)
+    pub fn gateway(&self) -> String {
+        // Unwrap is safe here because internal pointer is guaranteed to be valid
+        // SDK only returns error for this function if pointer is NULL
+        // Ref: https://github.com/orbbec/OrbbecSDK_v2/blob/815ae047cc977a1f7edd2b97b69ff6cd29f510b3/src/impl/Device.cpp (ob_device_info_get_gateway)
+        let cstr = self.inner.get_gateway().unwrap();
+
+        cstr.to_string_lossy().into_owned()
+    }
+
     /// Get the device minimum supported SDK version
     pub fn minimum_supported_sdk_version(&self) -> String {
         // Unwrap is safe here because internal pointer is guaranteed to be valid
@@ -131,6 +181,9 @@ impl fmt::Debug for DeviceInfo {
             .field("firmware_version", &self.firmware_version())
             .field("hardware_version", &self.hardware_version())
             .field("connection_type", &self.connection_type())
+            .field("ip_address", &self.ip_address())
+            .field("subnet_mask", &self.subnet_mask())
+            .field("gateway", &self.gateway())
             .field(
                 "minimum_supported_sdk_version",
                 &self.minimum_supported_sdk_version(),
@@ -141,14 +194,78 @@ impl fmt::Debug for DeviceInfo {
     }
 }
 
+/// A depth work mode of a device
+///
+/// Obtained from [`Device::depth_work_modes`] or [`Device::current_depth_work_mode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepthWorkMode {
+    name: String,
+    checksum: [u8; 16],
+    tag: DepthWorkModeTag,
+}
+
+impl DepthWorkMode {
+    /// Get the name of the work mode
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the checksum of the work mode
+    pub fn checksum(&self) -> &[u8; 16] {
+        &self.checksum
+    }
+
+    /// Get whether the work mode is built into the device or custom
+    pub fn tag(&self) -> DepthWorkModeTag {
+        self.tag
+    }
+}
+
+impl From<OBDepthWorkMode> for DepthWorkMode {
+    fn from(ob: OBDepthWorkMode) -> Self {
+        let name_bytes = ob.name.map(|c| c as u8);
+        let len = name_bytes
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(name_bytes.len());
+
+        DepthWorkMode {
+            name: String::from_utf8_lossy(&name_bytes[..len]).into_owned(),
+            checksum: ob.checksum,
+            tag: { ob.tag },
+        }
+    }
+}
+
+impl From<&DepthWorkMode> for OBDepthWorkMode {
+    fn from(mode: &DepthWorkMode) -> Self {
+        // Name always fits because it was read from the same buffer size
+        let mut name = [0; 32];
+        for (dst, src) in name.iter_mut().zip(mode.name.bytes().take(31)) {
+            *dst = src as std::ffi::c_char;
+        }
+
+        OBDepthWorkMode {
+            checksum: mode.checksum,
+            name,
+            tag: mode.tag,
+        }
+    }
+}
+
 /// A single Orbbec device
 pub struct Device {
     pub(crate) inner: sys::device::OBDevice,
+    /// The SDK keeps a single state changed callback per device
+    state_callback_registered: AtomicBool,
 }
 
 impl Device {
     pub(crate) fn new(inner: sys::device::OBDevice) -> Self {
-        Device { inner }
+        Device {
+            inner,
+            state_callback_registered: AtomicBool::new(false),
+        }
     }
 
     pub(crate) fn inner(&self) -> &sys::device::OBDevice {
@@ -162,23 +279,118 @@ impl Device {
         Ok(DeviceInfo::new(info))
     }
 
+    /// Get the list of sensors of the device
+    pub fn sensors(&self) -> Result<SensorList<'_>, OrbbecError> {
+        self.inner
+            .get_sensor_list()
+            .map(SensorList::new)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the post-processing filters recommended by the SDK for the depth sensor
+    ///
+    /// The filters come with parameters tuned for the device, some of them may be disabled by default.
+    pub fn recommended_depth_filters(&self) -> Result<FilterChain, OrbbecError> {
+        let Some(sensor) = self.sensors()?.get_by_type(SensorType::Depth)? else {
+            let err_data = OrbbecErrorData {
+                message: "Device has no depth sensor".to_string(),
+                function: "Device::recommended_depth_filters".to_string(),
+                args: String::new(),
+            };
+
+            return Err(OrbbecError::NotFound(err_data));
+        };
+
+        sensor.recommended_filters()
+    }
+
     /// Load a preset configuration to the device
     /// ### Arguments
     /// * `preset_name` - The name of the preset to load
     pub fn load_preset(&mut self, preset_name: &str) -> Result<(), OrbbecError> {
-        let cstr = std::ffi::CString::new(preset_name).map_err(|e| {
-            let err_data = OrbbecErrorData {
-                message: format!("Invalid preset name: {e}"),
-                function: "Device::load_preset".to_string(),
-                args: preset_name.to_string(),
-            };
-
-            OrbbecError::InvalidValue(err_data)
-        })?;
+        let cstr = to_cstring(preset_name, "preset name", "Device::load_preset")?;
 
         self.inner.load_preset(&cstr).map_err(OrbbecError::from)
     }
 
+    /// Get the names of the presets available on the device
+    pub fn available_presets(&self) -> Result<Vec<String>, OrbbecError> {
+        let list = self
+            .inner
+            .get_available_preset_list()
+            .map_err(OrbbecError::from)?;
+        let count = list.get_count().map_err(OrbbecError::from)?;
+
+        (0..count)
+            .map(|i| {
+                list.get_name(i)
+                    .map(|cstr| cstr.to_string_lossy().into_owned())
+                    .map_err(OrbbecError::from)
+            })
+            .collect()
+    }
+
+    /// Get the name of the preset currently in use
+    pub fn current_preset(&self) -> Result<String, OrbbecError> {
+        self.inner
+            .get_current_preset_name()
+            .map(|cstr| cstr.to_string_lossy().into_owned())
+            .map_err(OrbbecError::from)
+    }
+
+    /// Load a preset from json data and apply it to the device
+    ///
+    /// The preset is added to the available presets under the given name.
+    /// ### Arguments
+    /// * `preset_name` - The name to register the preset under
+    /// * `data` - The preset json data, as produced by [`Device::export_preset_json`]
+    pub fn load_preset_json(&mut self, preset_name: &str, data: &[u8]) -> Result<(), OrbbecError> {
+        let cstr = to_cstring(preset_name, "preset name", "Device::load_preset_json")?;
+
+        self.inner
+            .load_preset_from_json_data(&cstr, data)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Export the current device settings as preset json data
+    ///
+    /// The preset is added to the available presets under the given name.
+    /// ### Arguments
+    /// * `preset_name` - The name to register the preset under
+    pub fn export_preset_json(&mut self, preset_name: &str) -> Result<Vec<u8>, OrbbecError> {
+        let cstr = to_cstring(preset_name, "preset name", "Device::export_preset_json")?;
+
+        // The SDK reuses the buffer on the next call, so copy it out
+        self.inner
+            .export_current_settings_as_preset_json_data(&cstr)
+            .map(|data| data.to_vec())
+            .map_err(OrbbecError::from)
+    }
+
+    /// Load a preset from a json file and apply it to the device
+    ///
+    /// The preset is added to the available presets, named as the file name.
+    /// ### Arguments
+    /// * `file_path` - Path of the preset json file
+    pub fn load_preset_file(&mut self, file_path: &Path) -> Result<(), OrbbecError> {
+        let c_path = path_to_cstring(file_path, "Device::load_preset_file")?;
+
+        self.inner
+            .load_preset_from_json_file(&c_path)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Export the current device settings to a preset json file
+    /// ### Arguments
+    /// * `file_path` - Path of the preset json file to write
+    pub fn export_preset_file(&mut self, file_path: &Path) -> Result<(), OrbbecError> {
+        let c_path = path_to_cstring(file_path, "Device::export_preset_file")?;
+
+        self.inner
+            .export_current_settings_as_preset_json_file(&c_path)
+            .map_err(OrbbecError::from)
+    }
+
     /// Check if a device property is supported.
     ///
     /// ### Type Parameters
@@ -221,6 +433,551 @@ impl Device {
         P::get_from(self)
     }
 
+    /// Set a property value on the device, selecting the property by name
+    ///
+    /// Integer values are accepted for float properties.
+    /// ### Arguments
+    /// * `name` - The name of the property, same as the typed property struct (e.g. `"ColorExposure"`)
+    /// * `value` - The value to set
+    pub fn set_property_by_name(
+        &mut self,
+        name: &str,
+        value: PropertyValue,
+    ) -> Result<(), OrbbecError> {
+        let (id, property_type) = lookup_property(name, "Device::set_property_by_name")?;
+
+        let res = match (property_type, value) {
+            (PropertyType::BoolProperty, PropertyValue::Bool(v)) => {
+                self.inner.set_bool_property(id, v)
+            }
+            (PropertyType::IntProperty, PropertyValue::Int(v)) => {
+                self.inner.set_int_property(id, v)
+            }
+            (PropertyType::FloatProperty, PropertyValue::Float(v)) => {
+                self.inner.set_float_property(id, v)
+            }
+            (PropertyType::FloatProperty, PropertyValue::Int(v)) => {
+                self.inner.set_float_property(id, v as f32)
+            }
+            _ => {
+                let err_data = OrbbecErrorData {
+                    message: format!(
+                        "Property {name} expects a {property_type:?} value, got {value:?}"
+                    ),
+                    function: "Device::set_property_by_name".to_string(),
+                    args: format!("{name}, {value:?}"),
+                };
+
+                return Err(OrbbecError::InvalidValue(err_data));
+            }
+        };
+
+        res.map_err(OrbbecError::from)
+    }
+
+    /// Get a property value from the device, selecting the property by name
+    /// ### Arguments
+    /// * `name` - The name of the property, same as the typed property struct (e.g. `"ColorExposure"`)
+    pub fn get_property_by_name(&self, name: &str) -> Result<PropertyValue, OrbbecError> {
+        let (id, property_type) = lookup_property(name, "Device::get_property_by_name")?;
+
+        let res = match property_type {
+            PropertyType::BoolProperty => self.inner.get_bool_property(id).map(PropertyValue::Bool),
+            PropertyType::IntProperty => self.inner.get_int_property(id).map(PropertyValue::Int),
+            _ => self.inner.get_float_property(id).map(PropertyValue::Float),
+        };
+
+        res.map_err(OrbbecError::from)
+    }
+
+    /// Get the list of properties supported by the device
+    ///
+    /// Use [`PropertyItem::is`] to match an item against a typed property.
+    pub fn supported_properties(&self) -> Result<Vec<PropertyItem>, OrbbecError> {
+        let count = self
+            .inner
+            .get_supported_property_count()
+            .map_err(OrbbecError::from)?;
+
+        (0..count)
+            .map(|i| {
+                self.inner
+                    .get_supported_property_item(i)
+                    .map(PropertyItem::from)
+                    .map_err(OrbbecError::from)
+            })
+            .collect()
+    }
+
+    /// Get the range of values accepted by a device property
+    ///
+    /// ### Type Parameters
+    /// * `P` - The struct implementing [`RangedProperty`] to query (e.g., `ColorExposure`).
+    ///
+    /// ### Returns
+    /// Returns the min, max, step, default and current values, or an [`OrbbecError`].
+    pub fn property_range<P: RangedProperty>(
+        &self,
+    ) -> Result<PropertyRange<P::Value>, OrbbecError> {
+        P::range_from(self)
+    }
+
+    /// Set a property value on the device, after checking it against the property range
+    ///
+    /// Returns [`OrbbecError::InvalidValue`] without writing to the device if the value is out of range.
+    ///
+    /// ### Type Parameters
+    /// * `P` - The struct implementing [`SetProperty`] and [`RangedProperty`] to set (e.g., `ColorExposure`).
+    ///
+    /// ### Arguments
+    /// * `value` - The value to set
+    pub fn set_property_checked<P: SetProperty + RangedProperty>(
+        &mut self,
+        value: P::Value,
+    ) -> Result<(), OrbbecError> {
+        let range = P::range_from(self)?;
+
+        if !range.contains(value) {
+            let err_data = OrbbecErrorData {
+                message: format!(
+                    "Value {value:?} is out of range (min: {:?}, max: {:?}, step: {:?})",
+                    range.min, range.max, range.step
+                ),
+                function: "Device::set_property_checked".to_string(),
+                args: format!("{:?}, {value:?}", P::ID),
+            };
+
+            return Err(OrbbecError::InvalidValue(err_data));
+        }
+
+        P::set_on(self, value)
+    }
+
+    /// Read every readable property supported by the device into a snapshot
+    ///
+    /// Covers bool, int and float properties, plus struct properties with a known value type.
+    /// Read-only properties (e.g. `DeviceTemperature`) are included to detect drift,
+    /// [`Device::apply_snapshot`] skips them.
+    /// Command-like properties (e.g. `DeviceRepower`) are skipped.
+    /// Properties that fail to be read are recorded in [`PropertySnapshot::unreadable`].
+    pub fn snapshot_properties(&self) -> Result<PropertySnapshot, OrbbecError> {
+        let mut snapshot = PropertySnapshot::new();
+
+        for item in self.supported_properties()? {
+            if !item.is_readable() {
+                continue;
+            }
+
+            let (name, value) = if let Some((name, property_type)) = scalar_property_name(item.id) {
+                let value = match property_type {
+                    PropertyType::BoolProperty => self
+                        .inner
+                        .get_bool_property(item.id)
+                        .map(SnapshotValue::Bool),
+                    PropertyType::IntProperty => {
+                        self.inner.get_int_property(item.id).map(SnapshotValue::Int)
+                    }
+                    _ => self
+                        .inner
+                        .get_float_property(item.id)
+                        .map(SnapshotValue::Float),
+                };
+                (name, value)
+            } else if let Some((name, size)) = struct_property_name(item.id) {
+                let value = self
+                    .inner
+                    .get_raw_struct_property(item.id, size)
+                    .map(SnapshotValue::Struct);
+                (name, value)
+            } else {
+                continue;
+            };
+
+            if SNAPSHOT_SKIPPED_PROPERTIES.contains(&name) {
+                continue;
+            }
+
+            // Some properties are listed as supported but can't be read in every state
+            match value {
+                Ok(value) => snapshot.insert(name, value),
+                Err(_) => snapshot.insert_unreadable(name),
+            };
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Write the values of a snapshot back to the device
+    ///
+    /// Properties that are not writable on this device are skipped.
+    /// Automatic modes (e.g. `ColorAutoExposure`) being disabled are written before their manual
+    /// values (e.g. `ColorExposure`) and automatic modes being enabled after them,
+    /// so that manual values are not rejected or overridden.
+    /// Stops at the first property that fails to be written.
+    /// ### Arguments
+    /// * `snapshot` - The snapshot to apply
+    pub fn apply_snapshot(&mut self, snapshot: &PropertySnapshot) -> Result<(), OrbbecError> {
+        let writable: Vec<PropertyId> = self
+            .supported_properties()?
+            .into_iter()
+            .filter(PropertyItem::is_writable)
+            .map(|item| item.id)
+            .collect();
+
+        let mut entries: Vec<(&str, &SnapshotValue)> = snapshot
+            .iter()
+            .filter(|(name, _)| !SNAPSHOT_SKIPPED_PROPERTIES.contains(name))
+            .collect();
+        entries.sort_by_key(|(name, value)| write_order(name, value));
+
+        for (name, value) in entries {
+            let res = match value {
+                SnapshotValue::Struct(data) => {
+                    let (id, size) = find_struct_property(name)
+                        .ok_or_else(|| unknown_property(name, "Device::apply_snapshot"))?;
+                    if !writable.contains(&id) {
+                        continue;
+                    }
+                    if data.len() > size {
+                        let err_data = OrbbecErrorData {
+                            message: format!(
+                                "Property {name} expects at most {size} bytes, got {}",
+                                data.len()
+                            ),
+                            function: "Device::apply_snapshot".to_string(),
+                            args: name.to_string(),
+                        };
+
+                        return Err(OrbbecError::InvalidValue(err_data));
+                    }
+
+                    self.inner.set_raw_struct_property(id, data)
+                }
+                _ => {
+                    let (id, _) = lookup_property(name, "Device::apply_snapshot")?;
+                    if !writable.contains(&id) {
+                        continue;
+                    }
+
+                    match *value {
+                        SnapshotValue::Bool(v) => self.inner.set_bool_property(id, v),
+                        SnapshotValue::Int(v) => self.inner.set_int_property(id, v),
+                        SnapshotValue::Float(v) => self.inner.set_float_property(id, v),
+                        SnapshotValue::Struct(_) => unreachable!(),
+                    }
+                }
+            };
+
+            res.map_err(OrbbecError::from)?;
+        }
+
+        Ok(())
+    }
+
+    /// Compare the version of a firmware image against the firmware running on the device
+    ///
+    /// Nothing is written to the device, use it as a dry run before [`Device::update_firmware`].
+    /// ### Arguments
+    /// * `image_version` - Version of the firmware image (e.g. from [`FirmwareVersion::from_file_name`])
+    pub fn check_firmware_version(
+        &self,
+        image_version: &FirmwareVersion,
+    ) -> Result<FirmwareCheck, OrbbecError> {
+        let firmware_version = self.info()?.firmware_version();
+        let device_version = FirmwareVersion::parse(&firmware_version).ok_or_else(|| {
+            let err_data = OrbbecErrorData {
+                message: format!("Unable to parse device firmware version: {firmware_version}"),
+                function: "Device::check_firmware_version".to_string(),
+                args: image_version.to_string(),
+            };
+
+            OrbbecError::InvalidValue(err_data)
+        })?;
+
+        Ok(FirmwareCheck {
+            device_version,
+            image_version: image_version.clone(),
+        })
+    }
+
+    /// Update the device firmware, blocking until the update finishes
+    ///
+    /// The device must be rebooted afterwards for the new firmware to take effect.
+    /// ### Arguments
+    /// * `source` - Firmware image, either a file path or its content
+    /// * `progress` - Called with the update state, a status message and the progress percentage
+    ///
+    /// ### Returns
+    /// Returns the final update state, or an [`OrbbecError`] if the update failed.
+    pub fn update_firmware<'a, S, F>(
+        &mut self,
+        source: S,
+        mut progress: F,
+    ) -> Result<UpdateState, OrbbecError>
+    where
+        S: Into<FirmwareSource<'a>>,
+        F: FnMut(UpdateState, &str, u8),
+    {
+        let mut last_state = None;
+        let mut callback = |state: UpdateState, message: &str, percent: u8| {
+            last_state = Some((state, message.to_string()));
+            progress(state, message, percent);
+        };
+        let mut callback_ref: &mut dyn FnMut(UpdateState, &str, u8) = &mut callback;
+        let user_data = &mut callback_ref as *mut _ as *mut c_void;
+
+        match source.into() {
+            FirmwareSource::Path(path) => {
+                let path = path_to_cstring(path, "Device::update_firmware")?;
+                self.inner
+                    .update_firmware(&path, Some(fw_update_trampoline), false, user_data)
+            }
+            FirmwareSource::Data(data) => {
+                if u32::try_from(data.len()).is_err() {
+                    let err_data = OrbbecErrorData {
+                        message: format!("Firmware image is too large: {} bytes", data.len()),
+                        function: "Device::update_firmware".to_string(),
+                        args: "data".to_string(),
+                    };
+
+                    return Err(OrbbecError::InvalidValue(err_data));
+                }
+
+                self.inner.update_firmware_from_data(
+                    data,
+                    Some(fw_update_trampoline),
+                    false,
+                    user_data,
+                )
+            }
+        }
+        .map_err(OrbbecError::from)?;
+
+        check_update_state(last_state, "Device::update_firmware")
+    }
+
+    /// Update the optional depth presets of the device, blocking until the update finishes
+    /// ### Arguments
+    /// * `file_paths` - Paths to the preset files
+    /// * `progress` - Called with the update state, a status message and the progress percentage
+    ///
+    /// ### Returns
+    /// Returns the final update state, or an [`OrbbecError`] if the update failed.
+    pub fn update_optional_depth_presets<F>(
+        &mut self,
+        file_paths: &[&Path],
+        mut progress: F,
+    ) -> Result<UpdateState, OrbbecError>
+    where
+        F: FnMut(UpdateState, &str, u8),
+    {
+        const FUNCTION: &str = "Device::update_optional_depth_presets";
+
+        if file_paths.len() > u8::MAX as usize {
+            let err_data = OrbbecErrorData {
+                message: format!("Too many preset files: {}", file_paths.len()),
+                function: FUNCTION.to_string(),
+                args: "file_paths".to_string(),
+            };
+
+            return Err(OrbbecError::InvalidValue(err_data));
+        }
+
+        let mut path_list = Vec::with_capacity(file_paths.len());
+        for path in file_paths {
+            let path = path_to_cstring(path, FUNCTION)?;
+            let bytes = path.as_bytes_with_nul();
+
+            let mut entry = [0 as std::ffi::c_char; sys::orb::OB_PATH_MAX as usize];
+            if bytes.len() > entry.len() {
+                let err_data = OrbbecErrorData {
+                    message: format!("Preset file path is too long: {path:?}"),
+                    function: FUNCTION.to_string(),
+                    args: "file_paths".to_string(),
+                };
+
+                return Err(OrbbecError::InvalidValue(err_data));
+            }
+            for (dst, src) in entry.iter_mut().zip(bytes) {
+                *dst = *src as std::ffi::c_char;
+            }
+            path_list.push(entry);
+        }
+
+        let mut last_state = None;
+        let mut callback = |state: UpdateState, message: &str, percent: u8| {
+            last_state = Some((state, message.to_string()));
+            progress(state, message, percent);
+        };
+        let mut callback_ref: &mut dyn FnMut(UpdateState, &str, u8) = &mut callback;
+        let user_data = &mut callback_ref as *mut _ as *mut c_void;
+
+        self.inner
+            .update_optional_depth_presets(&path_list, Some(fw_update_trampoline), user_data)
+            .map_err(OrbbecError::from)?;
+
+        check_update_state(last_state, FUNCTION)
+    }
+
+    /// Reboot the device
+    ///
+    /// The device disconnects and the handle can no longer be used, so it is consumed.
+    /// Use [`Context::wait_for_device`] to open it again once it reconnects.
+    pub fn reboot(self) -> Result<(), OrbbecError> {
+        self.inner.reboot().map_err(OrbbecError::from)
+    }
+
+    /// Enable or disable the device heartbeat
+    ///
+    /// When enabled, the SDK sends a heartbeat to the device every 3 seconds and reports
+    /// the device state through [`Device::on_state_changed`].
+    /// A device that stops receiving the heartbeat disconnects and reboots itself.
+    /// ### Arguments
+    /// * `enable` - Whether to enable the heartbeat
+    pub fn enable_heartbeat(&mut self, enable: bool) -> Result<(), OrbbecError> {
+        self.inner
+            .enable_heartbeat(enable)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the current device state
+    ///
+    /// The state is a device-specific bit field reported by the firmware, zero when no issue is reported.
+    pub fn state(&self) -> Result<DeviceState, OrbbecError> {
+        self.inner.get_device_state().map_err(OrbbecError::from)
+    }
+
+    /// Register a callback invoked whenever the device state changes
+    ///
+    /// The callback receives the new state and a status message.
+    /// Only one callback can be registered per device, registering another one while
+    /// a handle is alive returns [`OrbbecError::WrongAPICallSequence`].
+    /// It remains registered for the lifetime of the returned handle.
+    #[must_use = "dropping the handle will unregister the callback"]
+    pub fn on_state_changed<F>(
+        &self,
+        callback: F,
+    ) -> Result<StateChangedCallbackHandle<'_>, OrbbecError>
+    where
+        F: FnMut(DeviceState, &str) + Send + 'static,
+    {
+        if self
+            .state_callback_registered
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            let err_data = OrbbecErrorData {
+                message: "A state changed callback is already registered".to_string(),
+                function: "Device::on_state_changed".to_string(),
+                args: "".to_string(),
+            };
+
+            return Err(OrbbecError::WrongAPICallSequence(err_data));
+        }
+
+        let boxed: StateChangedCallback = Box::new(Box::new(callback));
+        let user_data = boxed.as_ref() as *const _ as *mut c_void;
+
+        if let Err(e) = self
+            .inner
+            .set_state_changed_callback(Some(state_changed_trampoline), user_data)
+        {
+            self.state_callback_registered
+                .store(false, Ordering::SeqCst);
+            return Err(OrbbecError::from(e));
+        }
+
+        Ok(StateChangedCallbackHandle {
+            device: self,
+            _callback: boxed,
+        })
+    }
+
+    /// Write customer data to the device flash, replacing the previous data
+    ///
+    /// The data is kept across reboots and firmware updates.
+    /// ### Arguments
+    /// * `data` - The data to write, at most [`MAX_CUSTOMER_DATA_SIZE`] bytes
+    pub fn write_customer_data(&mut self, data: &[u8]) -> Result<(), OrbbecError> {
+        if data.len() > MAX_CUSTOMER_DATA_SIZE {
+            let err_data = OrbbecErrorData {
+                message: format!(
+                    "Customer data is {} bytes, the maximum is {MAX_CUSTOMER_DATA_SIZE}",
+                    data.len()
+                ),
+                function: "Device::write_customer_data".to_string(),
+                args: "data".to_string(),
+            };
+
+            return Err(OrbbecError::InvalidValue(err_data));
+        }
+
+        self.inner
+            .write_customer_data(data)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Read the customer data stored in the device flash
+    pub fn read_customer_data(&self) -> Result<Vec<u8>, OrbbecError> {
+        let mut data = vec![0u8; MAX_CUSTOMER_DATA_SIZE];
+        let size = self
+            .inner
+            .read_customer_data(&mut data)
+            .map_err(OrbbecError::from)?;
+
+        if size > data.len() {
+            let err_data = OrbbecErrorData {
+                message: format!(
+                    "Device reported {size} bytes of customer data, the maximum is {MAX_CUSTOMER_DATA_SIZE}"
+                ),
+                function: "Device::read_customer_data".to_string(),
+                args: String::new(),
+            };
+
+            return Err(OrbbecError::InvalidData(err_data));
+        }
+
+        data.truncate(size);
+        Ok(data)
+    }
+
+    /// Serialize a value as JSON and write it as the device customer data
+    /// ### Arguments
+    /// * `value` - The value to write, at most [`MAX_CUSTOMER_DATA_SIZE`] bytes once serialized
+    #[cfg(feature = "json")]
+    pub fn write_customer_json<T: serde::Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), OrbbecError> {
+        let data = serde_json::to_vec(value).map_err(|err| {
+            let err_data = OrbbecErrorData {
+                message: format!("Failed to serialize customer data: {err}"),
+                function: "Device::write_customer_json".to_string(),
+                args: "value".to_string(),
+            };
+
+            OrbbecError::InvalidValue(err_data)
+        })?;
+
+        self.write_customer_data(&data)
+    }
+
+    /// Read the device customer data and deserialize it from JSON
+    #[cfg(feature = "json")]
+    pub fn read_customer_json<T: serde::de::DeserializeOwned>(&self) -> Result<T, OrbbecError> {
+        let data = self.read_customer_data()?;
+
+        serde_json::from_slice(&data).map_err(|err| {
+            let err_data = OrbbecErrorData {
+                message: format!("Failed to deserialize customer data: {err}"),
+                function: "Device::read_customer_json".to_string(),
+                args: String::new(),
+            };
+
+            OrbbecError::InvalidValue(err_data)
+        })
+    }
+
     /// Check if the device supports global timestamp
     pub fn is_global_timestamp_supported(&self) -> Result<bool, OrbbecError> {
         self.inner
@@ -234,6 +991,179 @@ impl Device {
             .enable_global_timestamp(enabled)
             .map_err(OrbbecError::from)
     }
+
+    /// Get the multi-device sync modes supported by the device
+    pub fn supported_sync_modes(&self) -> Result<Vec<MultiDeviceSyncMode>, OrbbecError> {
+        let bitmap = self
+            .inner
+            .get_supported_multi_device_sync_mode_bitmap()
+            .map_err(OrbbecError::from)?;
+
+        Ok(sync_modes_from_bitmap(bitmap))
+    }
+
+    /// Set the multi-device sync configuration of the device
+    ///
+    /// Returns [`OrbbecError::UnsupportedOperation`] if the device does not support the sync mode.
+    /// ### Arguments
+    /// * `config` - The sync configuration to apply
+    pub fn set_sync_config(&mut self, config: &MultiDeviceSyncConfig) -> Result<(), OrbbecError> {
+        if !self.supported_sync_modes()?.contains(&config.mode()) {
+            let err_data = OrbbecErrorData {
+                message: format!(
+                    "Sync mode {:?} is not supported by the device",
+                    config.mode()
+                ),
+                function: "Device::set_sync_config".to_string(),
+                args: format!("{:?}", config.mode()),
+            };
+
+            return Err(OrbbecError::UnsupportedOperation(err_data));
+        }
+
+        self.inner
+            .set_multi_device_sync_config(&(*config).into())
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the multi-device sync configuration of the device
+    pub fn get_sync_config(&self) -> Result<MultiDeviceSyncConfig, OrbbecError> {
+        self.inner
+            .get_multi_device_sync_config()
+            .map(MultiDeviceSyncConfig::from)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the list of depth work modes supported by the device
+    pub fn depth_work_modes(&self) -> Result<Vec<DepthWorkMode>, OrbbecError> {
+        let list = self
+            .inner
+            .get_depth_work_mode_list()
+            .map_err(OrbbecError::from)?;
+        let count = list.get_count().map_err(OrbbecError::from)?;
+
+        (0..count)
+            .map(|i| {
+                list.get_item(i)
+                    .map(DepthWorkMode::from)
+                    .map_err(OrbbecError::from)
+            })
+            .collect()
+    }
+
+    /// Get the current depth work mode of the device
+    pub fn current_depth_work_mode(&self) -> Result<DepthWorkMode, OrbbecError> {
+        self.inner
+            .get_current_depth_work_mode()
+            .map(DepthWorkMode::from)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Switch the depth work mode of the device
+    ///
+    /// The device may reboot or reset its streams while switching.
+    /// ### Arguments
+    /// * `mode` - The work mode to switch to, as returned by [`Device::depth_work_modes`]
+    pub fn switch_depth_work_mode(&mut self, mode: &DepthWorkMode) -> Result<(), OrbbecError> {
+        let status = self
+            .inner
+            .switch_depth_work_mode(&mode.into())
+            .map_err(OrbbecError::from)?;
+
+        check_switch_status(status, "Device::switch_depth_work_mode", mode.name())
+    }
+
+    /// Switch the depth work mode of the device by name
+    /// ### Arguments
+    /// * `name` - The name of the work mode to switch to
+    pub fn switch_depth_work_mode_by_name(&mut self, name: &str) -> Result<(), OrbbecError> {
+        let cstr = to_cstring(
+            name,
+            "work mode name",
+            "Device::switch_depth_work_mode_by_name",
+        )?;
+
+        let status = self
+            .inner
+            .switch_depth_work_mode_by_name(&cstr)
+            .map_err(OrbbecError::from)?;
+
+        check_switch_status(status, "Device::switch_depth_work_mode_by_name", name)
+    }
+
+    /// Trigger a capture on the device
+    ///
+    /// Only works if the device is in [`MultiDeviceSyncMode::SoftwareTriggering`] mode.
+    pub fn trigger_capture(&self) -> Result<(), OrbbecError> {
+        self.inner.trigger_capture().map_err(OrbbecError::from)
+    }
+}
+
+/// Find a scalar property by name, returning a [`OrbbecError::NotFound`] if it does not exist
+fn lookup_property(name: &str, function: &str) -> Result<(PropertyId, PropertyType), OrbbecError> {
+    find_property(name).ok_or_else(|| unknown_property(name, function))
+}
+
+fn unknown_property(name: &str, function: &str) -> OrbbecError {
+    let err_data = OrbbecErrorData {
+        message: format!("Unknown property: {name}"),
+        function: function.to_string(),
+        args: name.to_string(),
+    };
+
+    OrbbecError::NotFound(err_data)
+}
+
+/// Properties that trigger an action on the device or that must not be copied between devices
+const SNAPSHOT_SKIPPED_PROPERTIES: &[&str] = &[
+    "BootIntoRecoveryMode",
+    "CaptureImageSignal",
+    "DeviceRebootDelay",
+    "DeviceIpAddrConfig",
+    "DeviceIpAddrConfigV2",
+    "DeviceRepower",
+    "DeviceTime",
+    "ExternalSignalReset",
+    "Heartbeat",
+    "LidarApplyConfigs",
+    "RestoreFactorySettings",
+    "TimerResetSignal",
+];
+
+/// Convert the status returned by a work mode switch into a result
+fn check_switch_status(status: OBErrorCode, function: &str, args: &str) -> Result<(), OrbbecError> {
+    if status == OBErrorCode::StatusOk {
+        return Ok(());
+    }
+
+    let err_data = OrbbecErrorData {
+        message: format!("Failed to switch depth work mode: {status:?}"),
+        function: function.to_string(),
+        args: args.to_string(),
+    };
+
+    Err(OrbbecError::Unknown(err_data))
+}
+
+/// Description of a device in a [`DeviceList`], obtained without opening the device
+///
+/// Unlike [`DeviceList::get`], this works even if the device is already held by another process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceListEntry {
+    /// Index of the device in the list
+    pub index: usize,
+    /// Device name
+    pub name: String,
+    /// Device USB product ID
+    pub pid: u16,
+    /// Device USB vendor ID
+    pub vid: u16,
+    /// Device unique identifier (based on the port it is connected, platform specific)
+    pub uid: String,
+    /// Device serial number
+    pub serial_number: String,
+    /// Device connection type
+    pub connection_type: String,
 }
 
 /// A list of Orbbec devices available
@@ -273,6 +1203,176 @@ impl<'a> DeviceList<'a> {
         device.map(Device::new).map_err(OrbbecError::from)
     }
 
+    /// Get the device with the specified serial number
+    /// ### Arguments
+    /// * `serial_number` - The serial number of the device to get
+    pub fn get_by_serial(&self, serial_number: &str) -> Result<Device, OrbbecError> {
+        let cstr = to_cstring(serial_number, "serial number", "DeviceList::get_by_serial")?;
+
+        self.get_by_serial_cstr(&cstr)
+    }
+
+    pub(crate) fn get_by_serial_cstr(&self, serial_number: &CStr) -> Result<Device, OrbbecError> {
+        self.inner
+            .get_device_by_serial_number(serial_number)
+            .map(Device::new)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Check if a device with the specified serial number is in the list, without opening it
+    pub(crate) fn contains_serial(&self, serial_number: &CStr) -> Result<bool, OrbbecError> {
+        for i in 0..self.len() {
+            let serial = self
+                .inner
+                .get_device_serial_number(i as u32)
+                .map_err(OrbbecError::from)?;
+            if serial == serial_number {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Get the device with the specified unique identifier
+    /// ### Arguments
+    /// * `uid` - The UID of the device to get (see [`DeviceInfo::uid`])
+    pub fn get_by_uid(&self, uid: &str) -> Result<Device, OrbbecError> {
+        let cstr = to_cstring(uid, "UID", "DeviceList::get_by_uid")?;
+
+        self.inner
+            .get_device_by_uid(&cstr)
+            .map(Device::new)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Describe the device at the specified index without opening it
+    /// ### Arguments
+    /// * `index` - The index of the device to describe
+    pub fn entry(&self, index: usize) -> Result<DeviceListEntry, OrbbecError> {
+        let i = index as u32;
+        let to_string = |cstr: &std::ffi::CStr| cstr.to_string_lossy().into_owned();
+
+        Ok(DeviceListEntry {
+            index,
+            name: self
+                .inner
+                .get_device_name(i)
+                .map(to_string)
+                .map_err(OrbbecError::from)?,
+            pid: self.inner.get_device_pid(i).map_err(OrbbecError::from)? as u16,
+            vid: self.inner.get_device_vid(i).map_err(OrbbecError::from)? as u16,
+            uid: self
+                .inner
+                .get_device_uid(i)
+                .map(to_string)
+                .map_err(OrbbecError::from)?,
+            serial_number: self
+                .inner
+                .get_device_serial_number(i)
+                .map(to_string)
+                .map_err(OrbbecError::from)?,
+            connection_type: self
+                .inner
+                .get_device_connection_type(i)
+                .map(to_string)
+                .map_err(OrbbecError::from)?,
+        })
+    }
+
+    /// Describe every device in the list without opening them
+    pub fn entries(&self) -> Result<Vec<DeviceListEntry>, OrbbecError> {
+        (0..self.len()).map(|i| self.entry(i)).collect()
+    }
+
+    /// Get the IP address of the device at the specified index ("0.0.0.0" for non-network devices)
+    /// ### Arguments
+    /// * `index` - The index of the device
+    pub fn ip_address(&self, index: usize) -> Result<String, OrbbecError> {
+        self.inner
+            .get_device_ip_address(index as u32)
+            .map(|cstr| cstr.to_string_lossy().into_owned())
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the subnet mask of the device at the specified index ("0.0.0.0" for non-network devices)
+    /// ### Arguments
+    /// * `index` - The index of the device
+    pub fn subnet_mask(&self, index: usize) -> Result<String, OrbbecError> {
+        self.inner
+            .get_device_subnet_mask(index as u32)
+            .map(|cstr| cstr.to_string_lossy().into_owned())
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the gateway of the device at the specified index ("0.0.0.0" for non-network devices)
+    /// ### Arguments
+    /// * `index` - The index of the device
+    pub fn gateway(&self, index: usize) -> Result<String, OrbbecError> {
+        self.inner
+            .get_device_gateway(index as u32)
+            .map(|cstr| cstr.to_string_lossy().into_owned())
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the MAC address of the host network interface the device at the specified index is connected to
+    /// ### Arguments
+    /// * `index` - The index of the device
+    pub fn local_mac(&self, index: usize) -> Result<String, OrbbecError> {
+        self.inner
+            .get_device_local_mac(index as u32)
+            .map(|cstr| cstr.to_string_lossy().into_owned())
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the IP address of the host network interface the device at the specified index is connected to
+    /// ### Arguments
+    /// * `index` - The index of the device
+    pub fn local_ip(&self, index: usize) -> Result<String, OrbbecError> {
+        self.inner
+            .get_device_local_ip(index as u32)
+            .map(|cstr| cstr.to_string_lossy().into_owned())
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the subnet length (0 to 32) of the host network interface the device at the specified index is connected to
+    /// ### Arguments
+    /// * `index` - The index of the device
+    pub fn local_subnet_length(&self, index: usize) -> Result<u8, OrbbecError> {
+        self.inner
+            .get_device_local_subnet_length(index as u32)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the gateway of the host network interface the device at the specified index is connected to
+    /// ### Arguments
+    /// * `index` - The index of the device
+    pub fn local_gateway(&self, index: usize) -> Result<String, OrbbecError> {
+        self.inner
+            .get_device_local_gateway(index as u32)
+            .map(|cstr| cstr.to_string_lossy().into_owned())
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the name of the host network interface (e.g. "eth0") the device at the specified index is connected to
+    /// ### Arguments
+    /// * `index` - The index of the device
+    pub fn local_interface_name(&self, index: usize) -> Result<String, OrbbecError> {
+        self.inner
+            .get_device_local_net_if_name(index as u32)
+            .map(|cstr| cstr.to_string_lossy().into_owned())
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the IP configuration source of the device at the specified index
+    /// ### Arguments
+    /// * `index` - The index of the device
+    pub fn ip_source_type(&self, index: usize) -> Result<IpSourceType, OrbbecError> {
+        self.inner
+            .get_device_ip_source_type(index as u32)
+            .map_err(OrbbecError::from)
+    }
+
     /// Get an iterator over the devices in the list.
     pub fn iter(&self) -> DeviceListIterator<'a, '_> {
         DeviceListIterator::new(self)
@@ -321,3 +1421,87 @@ where
         self.iter()
     }
 }
+
+pub(crate) type DevicesChangedCallback = Box<dyn FnMut(DeviceList<'_>, DeviceList<'_>) + Send>;
+
+/// Data handed to the SDK for the device changed callback
+pub(crate) struct DevicesChangedData {
+    pub(crate) context: *const Context,
+    pub(crate) callback: DevicesChangedCallback,
+}
+
+/// Handle that keeps a device changed callback registered.
+///
+/// The callback remains registered only while this handle is alive!
+#[must_use = "dropping the handle will unregister the callback"]
+pub struct DevicesChangedCallbackHandle<'a> {
+    context: &'a Context,
+    callback_id: u64,
+    _data: Box<DevicesChangedData>,
+}
+
+impl<'a> DevicesChangedCallbackHandle<'a> {
+    pub(crate) fn new(
+        context: &'a Context,
+        callback_id: u64,
+        data: Box<DevicesChangedData>,
+    ) -> Self {
+        DevicesChangedCallbackHandle {
+            context,
+            callback_id,
+            _data: data,
+        }
+    }
+}
+
+impl Drop for DevicesChangedCallbackHandle<'_> {
+    fn drop(&mut self) {
+        self.context
+            .inner
+            .unregister_device_changed_callback(self.callback_id)
+            .map_err(OrbbecError::from)
+            .unwrap();
+    }
+}
+
+type StateChangedCallback = Box<Box<dyn FnMut(DeviceState, &str) + Send>>;
+
+/// Handle that keeps a device state changed callback registered.
+///
+/// The callback remains registered only while this handle is alive!
+#[must_use = "dropping the handle will unregister the callback"]
+pub struct StateChangedCallbackHandle<'a> {
+    device: &'a Device,
+    _callback: StateChangedCallback,
+}
+
+impl Drop for StateChangedCallbackHandle<'_> {
+    fn drop(&mut self) {
+        // The SDK has no way to remove the callback, so replace it
+        // with one that doesn't reference the callback being freed
+        self.device
+            .inner
+            .set_state_changed_callback(Some(state_changed_noop), std::ptr::null_mut())
+            .map_err(OrbbecError::from)
+            .unwrap();
+
+        self.device
+            .state_callback_registered
+            .store(false, Ordering::SeqCst);
+    }
+}
+
+pub(crate) unsafe extern "C" fn devices_changed_trampoline(
+    removed: *mut crate::sys::orb::ob_device_list,
+    added: *mut crate::sys::orb::ob_device_list,
+    user_data: *mut c_void,
+) {
+    let data = unsafe { &mut *(user_data as *mut DevicesChangedData) };
+    let context = unsafe { &*data.context };
+
+    // The lists are owned by us and released when dropped
+    let removed = DeviceList::new(sys::device::OBDeviceList::new(removed), context);
+    let added = DeviceList::new(sys::device::OBDeviceList::new(added), context);
+
+    (data.callback)(removed, added);
+}
@@ -2,7 +2,7 @@
 use crate::sys::OBError;
 use crate::sys::orb::OBExceptionType;
 
-use std::{error::Error, fmt};
+use std::{error::Error, ffi::CString, fmt, path::Path};
 
 /// Detailed error information
 #[derive(Debug)]
@@ -122,3 +122,28 @@ impl From<OBError> for OrbbecError {
         OrbbecError::from(&err)
     }
 }
+
+/// Convert a string argument to a C string, failing if it contains a NUL byte
+/// ### Arguments
+/// * `value` - The string to convert
+/// * `what` - What the string is, used in the error message (e.g. `"preset name"`)
+/// * `function` - The function the string was passed to
+pub(crate) fn to_cstring(value: &str, what: &str, function: &str) -> Result<CString, OrbbecError> {
+    CString::new(value).map_err(|e| {
+        let err_data = OrbbecErrorData {
+            message: format!("Invalid {what}: {e}"),
+            function: function.to_string(),
+            args: value.to_string(),
+        };
+
+        OrbbecError::InvalidValue(err_data)
+    })
+}
+
+/// Convert a file path to a C string, failing if it contains a NUL byte
+/// ### Arguments
+/// * `path` - The path to convert
+/// * `function` - The function the path was passed to
+pub(crate) fn path_to_cstring(path: &Path, function: &str) -> Result<CString, OrbbecError> {
+    to_cstring(&path.to_string_lossy(), "file path", function)
+}
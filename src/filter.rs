@@ -1,12 +1,28 @@
 //! Filter module
+use std::ffi::CString;
+use std::sync::atomic::Ordering;
+
 use crate::{
-    ConvertType, CoordinateSystem, Format, HoleFillMode, StreamType,
-    error::{OrbbecError, OrbbecErrorData},
-    frame::{ColorFrame, DepthFrame, Frame, FrameSet, PointCloudFrame},
+    ConvertType, CoordinateSystem, EdgeNoiseRemovalType, FilterConfigValueType, Format,
+    HoleFillMode, StreamType,
+    error::{OrbbecError, OrbbecErrorData, to_cstring},
+    frame::{
+        AccelFrame, ColorFrame, DepthFrame, Frame, FrameSet, GyroFrame, IrFrame, PointCloudFrame,
+        check_frame_type,
+    },
     stream::VideoStreamProfile,
-    sys::filter::OBFilter,
+    sys::{
+        filter::{
+            OBFilter, OBFilterConfigSchemaItem, OBFilterList, frame_release_trampoline,
+            get_vendor_specific_code,
+        },
+        frame::OBFrame,
+        sensor::frame_trampoline,
+    },
 };
 
+type FilterCallback = Box<Box<dyn FnMut(OBFrame) + Send>>;
+
 /// Filter trait
 ///
 /// F1: Input frame type.
@@ -19,6 +35,606 @@ pub trait Filter<F1: Frame, F2: Frame>: AsRef<OBFilter> {
             .map(|f| F2::from(f))
             .map_err(OrbbecError::from)
     }
+
+    /// Set a callback invoked with each frame processed asynchronously
+    ///
+    /// Frames given to [`Filter::push`] are processed on an internal thread of the filter.
+    /// It remains registered for the lifetime of the returned handle.
+    /// The SDK keeps a single callback per filter, so an error is returned
+    /// if a callback is already registered.
+    /// The filter can't be configured (e.g. with [`FilterControl::set_config`] or
+    /// [`FilterControl::enable`]) while the handle is alive.
+    ///
+    /// ### Arguments
+    /// * `callback` - Called on each processed frame; must be `Send + 'static`
+    #[must_use = "dropping the handle will unregister the callback"]
+    fn set_callback<C>(&self, mut callback: C) -> Result<FilterCallbackHandle<'_>, OrbbecError>
+    where
+        Self: Sized,
+        C: FnMut(F2) + Send + 'static,
+    {
+        register_callback(self.as_ref(), move |frame| callback(F2::from(frame)))
+    }
+
+    /// Queue a frame for asynchronous processing, without blocking
+    ///
+    /// The processed frame is delivered to the callback set with [`Filter::set_callback`].
+    /// ### Arguments
+    /// * `frame` - The frame to process
+    fn push(&self, frame: &F1) -> Result<(), OrbbecError> {
+        self.as_ref()
+            .push_frame(frame.as_ref())
+            .map_err(OrbbecError::from)
+    }
+}
+
+fn register_callback<C>(
+    filter: &OBFilter,
+    callback: C,
+) -> Result<FilterCallbackHandle<'_>, OrbbecError>
+where
+    C: FnMut(OBFrame) + Send + 'static,
+{
+    if filter
+        .callback_registered
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+        .is_err()
+    {
+        let err_data = OrbbecErrorData {
+            message: "A callback is already registered".to_string(),
+            function: "Filter::set_callback".to_string(),
+            args: "".to_string(),
+        };
+
+        return Err(OrbbecError::WrongAPICallSequence(err_data));
+    }
+
+    let boxed: FilterCallback = Box::new(Box::new(callback));
+    let user_data = boxed.as_ref() as *const _ as *mut std::ffi::c_void;
+
+    if let Err(e) = filter.set_callback(Some(frame_trampoline), user_data) {
+        filter.callback_registered.store(false, Ordering::SeqCst);
+        return Err(OrbbecError::from(e));
+    }
+
+    Ok(FilterCallbackHandle {
+        filter,
+        _callback: boxed,
+    })
+}
+
+/// Handle that keeps an asynchronous filter callback registered.
+///
+/// The callback remains registered only while this handle is alive!
+#[must_use = "dropping the handle will unregister the callback"]
+pub struct FilterCallbackHandle<'a> {
+    filter: &'a OBFilter,
+    _callback: FilterCallback,
+}
+
+impl Drop for FilterCallbackHandle<'_> {
+    fn drop(&mut self) {
+        // The SDK has no way to remove the callback, so replace it
+        // with one that doesn't reference the callback being freed
+        self.filter
+            .set_callback(Some(frame_release_trampoline), std::ptr::null_mut())
+            .map_err(OrbbecError::from)
+            .unwrap();
+
+        self.filter
+            .callback_registered
+            .store(false, Ordering::SeqCst);
+    }
+}
+
+/// A configuration item of a filter
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterConfigItem {
+    /// Name of the configuration item
+    pub name: String,
+    /// Value type of the configuration item
+    pub value_type: FilterConfigValueType,
+    /// Minimum value
+    pub min: f64,
+    /// Maximum value
+    pub max: f64,
+    /// Step between values
+    pub step: f64,
+    /// Default value
+    pub default: f64,
+    /// Description of the configuration item
+    pub description: String,
+}
+
+impl FilterConfigItem {
+    /// Check if a value is accepted by the configuration item
+    ///
+    /// The value must be within the range, and be a whole number for int and boolean items.
+    /// ### Arguments
+    /// * `value` - The value to check
+    pub fn contains(&self, value: f64) -> bool {
+        if !(self.min..=self.max).contains(&value) {
+            return false;
+        }
+
+        match self.value_type {
+            FilterConfigValueType::Int | FilterConfigValueType::Boolean => value.fract() == 0.0,
+            _ => true,
+        }
+    }
+}
+
+impl From<OBFilterConfigSchemaItem> for FilterConfigItem {
+    fn from(item: OBFilterConfigSchemaItem) -> Self {
+        FilterConfigItem {
+            name: item.name().to_string_lossy().into_owned(),
+            value_type: item.value_type(),
+            min: item.minimum(),
+            max: item.maximum(),
+            step: item.step(),
+            default: item.default(),
+            description: item.description().to_string_lossy().into_owned(),
+        }
+    }
+}
+
+/// Configuration schema of a filter, listing its configuration items
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FilterSchema {
+    items: Vec<FilterConfigItem>,
+}
+
+impl FilterSchema {
+    /// Get the configuration item with the specified name
+    /// ### Arguments
+    /// * `name` - The name of the configuration item
+    pub fn get(&self, name: &str) -> Option<&FilterConfigItem> {
+        self.items.iter().find(|item| item.name == name)
+    }
+
+    /// Get the number of configuration items
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Check if the filter has no configuration items
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Get an iterator over the configuration items
+    pub fn iter(&self) -> std::slice::Iter<'_, FilterConfigItem> {
+        self.items.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a FilterSchema {
+    type Item = &'a FilterConfigItem;
+    type IntoIter = std::slice::Iter<'a, FilterConfigItem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Introspection and generic configuration, available on every filter
+pub trait FilterControl: AsRef<OBFilter> {
+    /// Get the name of the filter
+    fn name(&self) -> String {
+        // Unwrap is safe here because internal pointer is guaranteed to be valid
+        // SDK only returns error for this function if pointer is NULL
+        let cstr = self.as_ref().get_name().unwrap();
+
+        cstr.to_string_lossy().into_owned()
+    }
+
+    /// Get the configuration schema of the filter
+    fn schema(&self) -> Result<FilterSchema, OrbbecError> {
+        let list = self
+            .as_ref()
+            .get_config_schema_list()
+            .map_err(OrbbecError::from)?;
+        let count = list.get_count().map_err(OrbbecError::from)?;
+
+        let items = (0..count)
+            .map(|i| {
+                list.get_filter_config_item(i)
+                    .map(FilterConfigItem::from)
+                    .map_err(OrbbecError::from)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(FilterSchema { items })
+    }
+
+    /// Get a configuration value by name
+    /// ### Arguments
+    /// * `name` - The name of the configuration item (see [`FilterControl::schema`])
+    fn get_config(&self, name: &str) -> Result<f64, OrbbecError> {
+        let cstr = to_cstring(name, "config name", "FilterControl::get_config")?;
+
+        self.as_ref()
+            .get_config_value(&cstr)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Set a configuration value by name, after checking it against the schema
+    ///
+    /// Returns [`OrbbecError::NotFound`] for unknown items and [`OrbbecError::InvalidValue`]
+    /// for values out of range, without changing the filter.
+    /// ### Arguments
+    /// * `name` - The name of the configuration item (see [`FilterControl::schema`])
+    /// * `value` - The value to set, booleans are `0.0` or `1.0`
+    fn set_config(&mut self, name: &str, value: f64) -> Result<(), OrbbecError> {
+        let schema = self.schema()?;
+        let item = schema.get(name).ok_or_else(|| {
+            let err_data = OrbbecErrorData {
+                message: format!("Unknown filter config item: {name}"),
+                function: "FilterControl::set_config".to_string(),
+                args: format!("{name}, {value}"),
+            };
+
+            OrbbecError::NotFound(err_data)
+        })?;
+
+        if !item.contains(value) {
+            let err_data = OrbbecErrorData {
+                message: format!(
+                    "Value {value} is out of range for {:?} item {name} (min: {}, max: {})",
+                    item.value_type, item.min, item.max
+                ),
+                function: "FilterControl::set_config".to_string(),
+                args: format!("{name}, {value}"),
+            };
+
+            return Err(OrbbecError::InvalidValue(err_data));
+        }
+
+        let cstr = to_cstring(name, "config name", "FilterControl::set_config")?;
+        self.as_ref()
+            .set_config_value(&cstr, value)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Reset the filter, clearing its internal state (e.g. the history of a temporal filter)
+    fn reset(&mut self) -> Result<(), OrbbecError> {
+        self.as_ref().reset().map_err(OrbbecError::from)
+    }
+
+    /// Enable or disable the filter
+    ///
+    /// A disabled filter outputs a copy of its input frame.
+    /// ### Arguments
+    /// * `enable` - Whether to enable the filter
+    fn enable(&mut self, enable: bool) -> Result<(), OrbbecError> {
+        self.as_ref().enable(enable).map_err(OrbbecError::from)
+    }
+
+    /// Check if the filter is enabled
+    fn is_enabled(&self) -> Result<bool, OrbbecError> {
+        self.as_ref().is_enabled().map_err(OrbbecError::from)
+    }
+}
+
+impl<T: AsRef<OBFilter>> FilterControl for T {}
+
+/// Names of the filters known to be shipped with the SDK, see [`DynFilter::known_available`]
+const KNOWN_FILTER_NAMES: &[&str] = &[
+    "Align",
+    "DecimationFilter",
+    "DisparityTransform",
+    "EdgeNoiseRemovalFilter",
+    "FalsePositiveFilter",
+    "FormatConverter",
+    "HDRMerge",
+    "HoleFillingFilter",
+    "IMUCorrector",
+    "NoiseRemovalFilter",
+    "PixelValueScaler",
+    "PointCloudFilter",
+    "SequenceIdFilter",
+    "SpatialAdvancedFilter",
+    "SpatialFastFilter",
+    "SpatialModerateFilter",
+    "TemporalFilter",
+    "ThresholdFilter",
+];
+
+/// Filter created by name
+///
+/// Gives access to SDK filters without a typed wrapper, including private filters.
+/// Configure it through [`FilterControl`].
+/// Processing fails with [`OrbbecError::InvalidData`] if the filter outputs another frame type
+/// than requested.
+pub struct DynFilter {
+    inner: OBFilter,
+}
+
+impl DynFilter {
+    /// Create a filter by name
+    /// ### Arguments
+    /// * `name` - The name of the filter (e.g. `"TemporalFilter"`)
+    pub fn by_name(name: &str) -> Result<Self, OrbbecError> {
+        let cstr = to_cstring(name, "filter name", "DynFilter::by_name")?;
+
+        match OBFilter::new(&cstr)? {
+            Some(f) => Ok(DynFilter { inner: f }),
+            None => Err(filter_not_available(name, "DynFilter::by_name")),
+        }
+    }
+
+    /// Create a private filter by name
+    ///
+    /// Private filters are provided by vendors and may require an activation key.
+    /// ### Arguments
+    /// * `name` - The name of the filter
+    /// * `activation_key` - The activation key provided by the vendor of the filter
+    pub fn private(name: &str, activation_key: &str) -> Result<Self, OrbbecError> {
+        let cstr = to_cstring(name, "filter name", "DynFilter::private")?;
+        // Not using `to_cstring`, so that the key doesn't end up in the error arguments
+        let key = CString::new(activation_key).map_err(|e| {
+            let err_data = OrbbecErrorData {
+                message: format!("Invalid activation key: {e}"),
+                function: "DynFilter::private".to_string(),
+                args: name.to_string(),
+            };
+
+            OrbbecError::InvalidValue(err_data)
+        })?;
+
+        match OBFilter::new_private(&cstr, &key)? {
+            Some(f) => Ok(DynFilter { inner: f }),
+            None => Err(filter_not_available(name, "DynFilter::private")),
+        }
+    }
+
+    /// Get the vendor specific code of a filter
+    ///
+    /// Private filters can define their own code for vendor specific purposes.
+    /// ### Arguments
+    /// * `name` - The name of the filter
+    pub fn vendor_specific_code(name: &str) -> Result<String, OrbbecError> {
+        let cstr = to_cstring(name, "filter name", "DynFilter::vendor_specific_code")?;
+
+        get_vendor_specific_code(&cstr).map_err(OrbbecError::from)
+    }
+
+    /// Get the names of the filters known to the crate that are available in the loaded SDK
+    ///
+    /// The SDK has no way to enumerate its filters, so the list of names is fixed in the crate
+    /// and each of them is created once to check if it is available.
+    /// Filters added in newer SDK versions and private filters are not listed,
+    /// create them with [`DynFilter::by_name`] or [`DynFilter::private`].
+    pub fn known_available() -> Vec<String> {
+        KNOWN_FILTER_NAMES
+            .iter()
+            .filter(|name| Self::by_name(name).is_ok())
+            .map(|name| name.to_string())
+            .collect()
+    }
+}
+
+impl AsRef<OBFilter> for DynFilter {
+    fn as_ref(&self) -> &OBFilter {
+        &self.inner
+    }
+}
+
+/// Implement [`Filter`] for [`DynFilter`]
+///
+/// A filter created by name can output any frame type, so the type of the output frames is checked.
+macro_rules! impl_dyn_filter {
+    ($f1:ty, $f2:ty) => {
+        impl Filter<$f1, $f2> for DynFilter {
+            fn process(&self, frame: &$f1) -> Result<$f2, OrbbecError> {
+                let frame = self
+                    .inner
+                    .process(frame.as_ref())
+                    .map_err(OrbbecError::from)?;
+                check_frame_type::<$f2>(frame.get_type()?, "DynFilter::process")?;
+
+                Ok(<$f2>::from(frame))
+            }
+
+            /// Set a callback invoked with each frame processed asynchronously
+            ///
+            /// Processed frames that are not of the output type are dropped.
+            /// See [`Filter::set_callback`].
+            fn set_callback<C>(
+                &self,
+                mut callback: C,
+            ) -> Result<FilterCallbackHandle<'_>, OrbbecError>
+            where
+                Self: Sized,
+                C: FnMut($f2) + Send + 'static,
+            {
+                register_callback(&self.inner, move |frame| {
+                    let frame_type = frame.get_type();
+                    if frame_type.is_ok_and(|t| {
+                        check_frame_type::<$f2>(t, "DynFilter::set_callback").is_ok()
+                    }) {
+                        callback(<$f2>::from(frame));
+                    }
+                })
+            }
+        }
+    };
+}
+
+impl_dyn_filter!(DepthFrame, DepthFrame);
+impl_dyn_filter!(ColorFrame, ColorFrame);
+impl_dyn_filter!(IrFrame, IrFrame);
+impl_dyn_filter!(FrameSet, FrameSet);
+
+/// Ordered list of filters applied one after the other
+///
+/// Each filter can be toggled with [`FilterChain::set_enabled`] (or [`FilterControl::enable`]),
+/// disabled filters are skipped.
+/// Create the chain recommended by the SDK with [`crate::device::Device::recommended_depth_filters`].
+#[derive(Default)]
+pub struct FilterChain {
+    filters: Vec<DynFilter>,
+}
+
+impl FilterChain {
+    /// Create an empty filter chain
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn from_list(list: OBFilterList) -> Result<Self, OrbbecError> {
+        let count = list.get_count().map_err(OrbbecError::from)?;
+        let filters = (0..count)
+            .map(|i| list.get_filter(i).map(|inner| DynFilter { inner }))
+            .collect::<Result<_, _>>()
+            .map_err(OrbbecError::from)?;
+
+        Ok(FilterChain { filters })
+    }
+
+    /// Add a filter to the end of the chain
+    /// ### Arguments
+    /// * `filter` - The filter to add
+    pub fn push(&mut self, filter: DynFilter) {
+        self.filters.push(filter);
+    }
+
+    /// Remove a filter from the chain by name, returning it
+    /// ### Arguments
+    /// * `name` - The name of the filter
+    pub fn remove(&mut self, name: &str) -> Option<DynFilter> {
+        let index = self.filters.iter().position(|f| f.name() == name)?;
+        Some(self.filters.remove(index))
+    }
+
+    /// Get a filter of the chain by name
+    /// ### Arguments
+    /// * `name` - The name of the filter
+    pub fn get(&self, name: &str) -> Option<&DynFilter> {
+        self.filters.iter().find(|f| f.name() == name)
+    }
+
+    /// Get a mutable filter of the chain by name, to change its configuration
+    /// ### Arguments
+    /// * `name` - The name of the filter
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut DynFilter> {
+        self.filters.iter_mut().find(|f| f.name() == name)
+    }
+
+    /// Enable or disable a filter of the chain by name
+    /// ### Arguments
+    /// * `name` - The name of the filter
+    /// * `enable` - Whether the filter is applied
+    pub fn set_enabled(&mut self, name: &str, enable: bool) -> Result<(), OrbbecError> {
+        match self.get_mut(name) {
+            Some(filter) => filter.enable(enable),
+            None => {
+                let err_data = OrbbecErrorData {
+                    message: format!("{name} is not in the filter chain"),
+                    function: "FilterChain::set_enabled".to_string(),
+                    args: name.to_string(),
+                };
+
+                Err(OrbbecError::NotFound(err_data))
+            }
+        }
+    }
+
+    /// Get the number of filters in the chain
+    pub fn len(&self) -> usize {
+        self.filters.len()
+    }
+
+    /// Check if the chain is empty
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
+
+    /// Get an iterator over the filters, in processing order
+    pub fn iter(&self) -> std::slice::Iter<'_, DynFilter> {
+        self.filters.iter()
+    }
+
+    /// Get a mutable iterator over the filters, in processing order
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, DynFilter> {
+        self.filters.iter_mut()
+    }
+
+    /// Process a frame with every enabled filter of the chain
+    ///
+    /// The frame is returned as is if no filter is enabled.
+    /// Processing a frameset fails if a filter outputs another frame type,
+    /// see [`FilterChain::process_frameset`] to mix frameset and depth filters.
+    /// ### Arguments
+    /// * `frame` - The frame to process
+    pub fn process<F: Frame>(&self, frame: F) -> Result<F, OrbbecError>
+    where
+        DynFilter: Filter<F, F>,
+    {
+        let mut frame = frame;
+        for filter in &self.filters {
+            if filter.is_enabled()? {
+                frame = filter.process(&frame)?;
+            }
+        }
+
+        Ok(frame)
+    }
+
+    /// Process a frameset with every enabled filter of the chain
+    ///
+    /// Each filter is given the whole frameset, as some filters only process framesets
+    /// (e.g. `HDRMerge` or `SequenceIdFilter`).
+    /// Filters that only process depth frames output the processed depth frame alone,
+    /// it then replaces the depth frame of the frameset.
+    /// The frameset is returned as is if no filter is enabled.
+    /// ### Arguments
+    /// * `frameset` - The frameset to process
+    pub fn process_frameset(&self, frameset: FrameSet) -> Result<FrameSet, OrbbecError> {
+        let mut frameset = frameset;
+        for filter in &self.filters {
+            if !filter.is_enabled()? {
+                continue;
+            }
+
+            let frame = filter
+                .inner
+                .process(frameset.as_ref())
+                .map_err(OrbbecError::from)?;
+            let frame_type = frame.get_type()?;
+            if check_frame_type::<FrameSet>(frame_type, "FilterChain::process_frameset").is_ok() {
+                frameset = FrameSet::from(frame);
+                continue;
+            }
+
+            check_frame_type::<DepthFrame>(frame_type, "FilterChain::process_frameset")?;
+            frameset
+                .as_ref()
+                .push_frame(&frame)
+                .map_err(OrbbecError::from)?;
+        }
+
+        Ok(frameset)
+    }
+}
+
+impl<'a> IntoIterator for &'a FilterChain {
+    type Item = &'a DynFilter;
+    type IntoIter = std::slice::Iter<'a, DynFilter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+fn filter_not_available(name: &str, function: &str) -> OrbbecError {
+    let err_data = OrbbecErrorData {
+        message: format!("{name} is not available"),
+        function: function.to_string(),
+        args: name.to_string(),
+    };
+
+    OrbbecError::NotImplemented(err_data)
 }
 
 /// Decimation Filter
@@ -56,6 +672,7 @@ impl AsRef<OBFilter> for DecimationFilter {
 
 impl Filter<DepthFrame, DepthFrame> for DecimationFilter {}
 impl Filter<ColorFrame, ColorFrame> for DecimationFilter {}
+impl Filter<IrFrame, IrFrame> for DecimationFilter {}
 
 /// Format Convert Filter
 ///
@@ -91,6 +708,7 @@ impl AsRef<OBFilter> for FormatConvertFilter {
 
 impl Filter<DepthFrame, DepthFrame> for FormatConvertFilter {}
 impl Filter<ColorFrame, ColorFrame> for FormatConvertFilter {}
+impl Filter<IrFrame, IrFrame> for FormatConvertFilter {}
 
 /// Hole Filling Filter
 ///
@@ -581,3 +1199,346 @@ impl AsRef<OBFilter> for PointCloudFilter {
 
 impl Filter<DepthFrame, PointCloudFrame> for PointCloudFilter {}
 impl Filter<FrameSet, PointCloudFrame> for PointCloudFilter {}
+
+/// HDR Merge Filter
+///
+/// This filter merges the depth frames of an HDR sequence (captured with alternating exposures)
+/// into a single depth frame, keeping the detail of both dark and reflective surfaces.
+/// HDR capture must be enabled on the device with the `DepthHdrConfig` property.
+pub struct HdrMergeFilter {
+    inner: OBFilter,
+}
+
+impl HdrMergeFilter {
+    /// Create a new HDR merge filter.
+    pub fn new() -> Result<Self, OrbbecError> {
+        match OBFilter::new(c"HDRMerge")? {
+            Some(f) => Ok(HdrMergeFilter { inner: f }),
+            None => Err(filter_not_available("HDRMerge", "HdrMergeFilter::new")),
+        }
+    }
+}
+
+impl AsRef<OBFilter> for HdrMergeFilter {
+    fn as_ref(&self) -> &OBFilter {
+        &self.inner
+    }
+}
+
+impl Filter<FrameSet, FrameSet> for HdrMergeFilter {}
+
+/// Sequence ID Filter
+///
+/// This filter keeps only the frames captured with the selected sequence ID.
+/// With HDR capture enabled, it splits the stream by exposure.
+pub struct SequenceIdFilter {
+    inner: OBFilter,
+}
+
+impl SequenceIdFilter {
+    /// Create a new sequence ID filter.
+    pub fn new() -> Result<Self, OrbbecError> {
+        match OBFilter::new(c"SequenceIdFilter")? {
+            Some(f) => Ok(SequenceIdFilter { inner: f }),
+            None => Err(filter_not_available(
+                "SequenceIdFilter",
+                "SequenceIdFilter::new",
+            )),
+        }
+    }
+
+    /// Select the sequence ID of the frames to keep.
+    ///
+    /// ### Arguments
+    /// * `sequence_id` - The sequence ID, as reported by the [`crate::MetadataType::HdrSequenceIndex`] frame metadata.
+    pub fn set_sequence_id(&mut self, sequence_id: u8) -> Result<(), OrbbecError> {
+        self.inner
+            .set_config_value(c"sequenceid", sequence_id as f64)
+            .map_err(OrbbecError::from)
+    }
+}
+
+impl AsRef<OBFilter> for SequenceIdFilter {
+    fn as_ref(&self) -> &OBFilter {
+        &self.inner
+    }
+}
+
+impl Filter<FrameSet, FrameSet> for SequenceIdFilter {}
+impl Filter<DepthFrame, DepthFrame> for SequenceIdFilter {}
+impl Filter<IrFrame, IrFrame> for SequenceIdFilter {}
+
+/// Disparity Transform Filter
+///
+/// This filter converts disparity frames reported by the device into depth frames.
+pub struct DisparityTransformFilter {
+    inner: OBFilter,
+}
+
+impl DisparityTransformFilter {
+    /// Create a new disparity transform filter.
+    pub fn new() -> Result<Self, OrbbecError> {
+        match OBFilter::new(c"DisparityTransform")? {
+            Some(f) => Ok(DisparityTransformFilter { inner: f }),
+            None => Err(filter_not_available(
+                "DisparityTransform",
+                "DisparityTransformFilter::new",
+            )),
+        }
+    }
+}
+
+impl AsRef<OBFilter> for DisparityTransformFilter {
+    fn as_ref(&self) -> &OBFilter {
+        &self.inner
+    }
+}
+
+impl Filter<DepthFrame, DepthFrame> for DisparityTransformFilter {}
+
+/// Noise Removal Filter
+///
+/// This filter removes small isolated patches of depth (speckles) from the depth frame.
+pub struct NoiseRemovalFilter {
+    inner: OBFilter,
+}
+
+impl NoiseRemovalFilter {
+    /// Create a new noise removal filter.
+    pub fn new() -> Result<Self, OrbbecError> {
+        match OBFilter::new(c"NoiseRemovalFilter")? {
+            Some(f) => Ok(NoiseRemovalFilter { inner: f }),
+            None => Err(filter_not_available(
+                "NoiseRemovalFilter",
+                "NoiseRemovalFilter::new",
+            )),
+        }
+    }
+
+    /// Set the maximum size of a patch to be removed.
+    ///
+    /// ### Arguments
+    /// * `max_size` - The maximum size of a patch (in pixels).
+    pub fn set_max_size(&mut self, max_size: u16) -> Result<(), OrbbecError> {
+        self.inner
+            .set_config_value(c"max_size", max_size as f64)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Set the minimum difference between neighbor pixels for them to belong to different patches.
+    ///
+    /// ### Arguments
+    /// * `min_diff` - The minimum disparity difference.
+    pub fn set_min_diff(&mut self, min_diff: u16) -> Result<(), OrbbecError> {
+        self.inner
+            .set_config_value(c"min_diff", min_diff as f64)
+            .map_err(OrbbecError::from)
+    }
+}
+
+impl AsRef<OBFilter> for NoiseRemovalFilter {
+    fn as_ref(&self) -> &OBFilter {
+        &self.inner
+    }
+}
+
+impl Filter<DepthFrame, DepthFrame> for NoiseRemovalFilter {}
+
+/// Edge Noise Removal Filter
+///
+/// This filter removes the noise found at the edges of objects in the depth frame.
+pub struct EdgeNoiseRemovalFilter {
+    inner: OBFilter,
+}
+
+impl EdgeNoiseRemovalFilter {
+    /// Create a new edge noise removal filter.
+    pub fn new() -> Result<Self, OrbbecError> {
+        match OBFilter::new(c"EdgeNoiseRemovalFilter")? {
+            Some(f) => Ok(EdgeNoiseRemovalFilter { inner: f }),
+            None => Err(filter_not_available(
+                "EdgeNoiseRemovalFilter",
+                "EdgeNoiseRemovalFilter::new",
+            )),
+        }
+    }
+
+    /// Set the edge noise removal algorithm.
+    ///
+    /// ### Arguments
+    /// * `removal_type` - The removal algorithm.
+    pub fn set_type(&mut self, removal_type: EdgeNoiseRemovalType) -> Result<(), OrbbecError> {
+        self.inner
+            .set_config_value(c"noise_removal_type", removal_type as u32 as f64)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Set the thresholds of each margin.
+    ///
+    /// ### Arguments
+    /// * `left` - The left margin threshold.
+    /// * `right` - The right margin threshold.
+    /// * `top` - The top margin threshold.
+    /// * `bottom` - The bottom margin threshold.
+    pub fn set_margins(
+        &mut self,
+        left: u16,
+        right: u16,
+        top: u16,
+        bottom: u16,
+    ) -> Result<(), OrbbecError> {
+        let margins = [
+            (c"margin_left_th", left),
+            (c"margin_right_th", right),
+            (c"margin_top_th", top),
+            (c"margin_bottom_th", bottom),
+        ];
+
+        for (name, value) in margins {
+            self.inner
+                .set_config_value(name, value as f64)
+                .map_err(OrbbecError::from)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl AsRef<OBFilter> for EdgeNoiseRemovalFilter {
+    fn as_ref(&self) -> &OBFilter {
+        &self.inner
+    }
+}
+
+impl Filter<DepthFrame, DepthFrame> for EdgeNoiseRemovalFilter {}
+
+/// False Positive Filter
+///
+/// This filter removes depth values that are likely to be false positives.
+/// Its parameters depend on the SDK version, use [`FilterControl::schema`] to list them.
+pub struct FalsePositiveFilter {
+    inner: OBFilter,
+}
+
+impl FalsePositiveFilter {
+    /// Create a new false positive filter.
+    pub fn new() -> Result<Self, OrbbecError> {
+        match OBFilter::new(c"FalsePositiveFilter")? {
+            Some(f) => Ok(FalsePositiveFilter { inner: f }),
+            None => Err(filter_not_available(
+                "FalsePositiveFilter",
+                "FalsePositiveFilter::new",
+            )),
+        }
+    }
+}
+
+impl AsRef<OBFilter> for FalsePositiveFilter {
+    fn as_ref(&self) -> &OBFilter {
+        &self.inner
+    }
+}
+
+impl Filter<DepthFrame, DepthFrame> for FalsePositiveFilter {}
+
+/// Pixel Value Scale Filter
+///
+/// This filter multiplies every depth value by a scale factor,
+/// e.g. to compensate the depth unit of the device.
+pub struct PixelValueScaleFilter {
+    inner: OBFilter,
+}
+
+impl PixelValueScaleFilter {
+    /// Create a new pixel value scale filter.
+    pub fn new() -> Result<Self, OrbbecError> {
+        match OBFilter::new(c"PixelValueScaler")? {
+            Some(f) => Ok(PixelValueScaleFilter { inner: f }),
+            None => Err(filter_not_available(
+                "PixelValueScaler",
+                "PixelValueScaleFilter::new",
+            )),
+        }
+    }
+
+    /// Set the scale factor.
+    ///
+    /// ### Arguments
+    /// * `scale` - The scale factor.
+    pub fn set_scale(&mut self, scale: f32) -> Result<(), OrbbecError> {
+        self.inner
+            .set_config_value(c"scale", scale as f64)
+            .map_err(OrbbecError::from)
+    }
+}
+
+impl AsRef<OBFilter> for PixelValueScaleFilter {
+    fn as_ref(&self) -> &OBFilter {
+        &self.inner
+    }
+}
+
+impl Filter<DepthFrame, DepthFrame> for PixelValueScaleFilter {}
+
+/// IMU Correction Filter
+///
+/// This filter applies the factory calibration of the device to the accelerometer and gyroscope frames.
+pub struct ImuCorrectionFilter {
+    inner: OBFilter,
+}
+
+impl ImuCorrectionFilter {
+    /// Create a new IMU correction filter.
+    pub fn new() -> Result<Self, OrbbecError> {
+        match OBFilter::new(c"IMUCorrector")? {
+            Some(f) => Ok(ImuCorrectionFilter { inner: f }),
+            None => Err(filter_not_available(
+                "IMUCorrector",
+                "ImuCorrectionFilter::new",
+            )),
+        }
+    }
+}
+
+impl AsRef<OBFilter> for ImuCorrectionFilter {
+    fn as_ref(&self) -> &OBFilter {
+        &self.inner
+    }
+}
+
+impl Filter<AccelFrame, AccelFrame> for ImuCorrectionFilter {}
+impl Filter<GyroFrame, GyroFrame> for ImuCorrectionFilter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(value_type: FilterConfigValueType, min: f64, max: f64) -> FilterConfigItem {
+        FilterConfigItem {
+            name: "item".to_string(),
+            value_type,
+            min,
+            max,
+            step: 1.0,
+            default: min,
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_config_item_contains() {
+        let int = item(FilterConfigValueType::Int, 1.0, 8.0);
+        assert!(int.contains(1.0));
+        assert!(int.contains(8.0));
+        assert!(!int.contains(0.0));
+        assert!(!int.contains(2.5));
+
+        let float = item(FilterConfigValueType::Float, 0.1, 1.0);
+        assert!(float.contains(0.5));
+        assert!(!float.contains(1.5));
+
+        let boolean = item(FilterConfigValueType::Boolean, 0.0, 1.0);
+        assert!(boolean.contains(1.0));
+        assert!(!boolean.contains(0.5));
+    }
+}
@@ -0,0 +1,231 @@
+//! Firmware update module
+use std::cmp::Ordering;
+use std::fmt;
+use std::path::Path;
+
+use crate::UpdateState;
+use crate::error::{OrbbecError, OrbbecErrorData};
+
+impl UpdateState {
+    /// Check if the state reports a failed update
+    pub fn is_error(&self) -> bool {
+        (*self as i32) < 0
+    }
+
+    /// Check if the state reports a completed update
+    pub fn is_done(&self) -> bool {
+        matches!(
+            self,
+            UpdateState::StatDone
+                | UpdateState::StatDoneWithDuplicates
+                | UpdateState::StatDoneRebootAndReupdate
+        )
+    }
+}
+
+/// Firmware image to update the device with
+#[derive(Debug, Clone, Copy)]
+pub enum FirmwareSource<'a> {
+    /// Path to the firmware image file
+    Path(&'a Path),
+    /// Content of the firmware image file
+    Data(&'a [u8]),
+}
+
+impl<'a> From<&'a Path> for FirmwareSource<'a> {
+    fn from(path: &'a Path) -> Self {
+        FirmwareSource::Path(path)
+    }
+}
+
+impl<'a> From<&'a [u8]> for FirmwareSource<'a> {
+    fn from(data: &'a [u8]) -> Self {
+        FirmwareSource::Data(data)
+    }
+}
+
+impl<'a> From<&'a Vec<u8>> for FirmwareSource<'a> {
+    fn from(data: &'a Vec<u8>) -> Self {
+        FirmwareSource::Data(data)
+    }
+}
+
+/// Dotted firmware version number (e.g. `1.4.60`)
+#[derive(Debug, Clone)]
+pub struct FirmwareVersion {
+    parts: Vec<u32>,
+}
+
+impl FirmwareVersion {
+    /// Find the first dotted version number in a string
+    ///
+    /// Surrounding text is ignored, so both `"1.4.60"` and `"v1.4.60-rc"` are accepted.
+    /// ### Arguments
+    /// * `s` - The string containing the version
+    pub fn parse(s: &str) -> Option<Self> {
+        version_candidates(s).next()
+    }
+
+    /// Find the version number in the name of a firmware image file
+    ///
+    /// The last dotted version number of the file stem is used
+    /// (e.g. `Gemini330_Firmware_v1.4.60.bin` gives `1.4.60`).
+    /// ### Arguments
+    /// * `path` - Path to the firmware image file
+    pub fn from_file_name(path: &Path) -> Option<Self> {
+        let stem = path.file_stem()?.to_str()?;
+        version_candidates(stem).last()
+    }
+
+    /// Get the numeric parts of the version
+    pub fn parts(&self) -> &[u32] {
+        &self.parts
+    }
+}
+
+impl PartialEq for FirmwareVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for FirmwareVersion {}
+
+impl PartialOrd for FirmwareVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FirmwareVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Missing trailing parts count as zero, so 1.4 == 1.4.0
+        let len = self.parts.len().max(other.parts.len());
+        (0..len)
+            .map(|i| {
+                let a = self.parts.get(i).copied().unwrap_or(0);
+                let b = other.parts.get(i).copied().unwrap_or(0);
+                a.cmp(&b)
+            })
+            .find(|ord| ord.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl fmt::Display for FirmwareVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let parts: Vec<String> = self.parts.iter().map(u32::to_string).collect();
+        write!(f, "{}", parts.join("."))
+    }
+}
+
+/// Iterate over the dotted version numbers (at least two parts) found in a string
+fn version_candidates(s: &str) -> impl Iterator<Item = FirmwareVersion> + '_ {
+    s.split(|c: char| !c.is_ascii_digit() && c != '.')
+        .filter_map(|token| {
+            let parts: Option<Vec<u32>> = token
+                .trim_matches('.')
+                .split('.')
+                .map(|p| p.parse().ok())
+                .collect();
+
+            parts
+                .filter(|parts| parts.len() >= 2)
+                .map(|parts| FirmwareVersion { parts })
+        })
+}
+
+/// Result of comparing a firmware image version against the device firmware
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FirmwareCheck {
+    /// Firmware version currently running on the device
+    pub device_version: FirmwareVersion,
+    /// Firmware version of the image
+    pub image_version: FirmwareVersion,
+}
+
+impl FirmwareCheck {
+    /// Check if the image is newer than the device firmware
+    pub fn is_upgrade(&self) -> bool {
+        self.image_version > self.device_version
+    }
+
+    /// Check if the image is older than the device firmware
+    pub fn is_downgrade(&self) -> bool {
+        self.image_version < self.device_version
+    }
+
+    /// Check if the device already runs the image version
+    pub fn is_up_to_date(&self) -> bool {
+        self.image_version == self.device_version
+    }
+}
+
+/// Convert the final state of an update into a result
+pub(crate) fn check_update_state(
+    state: Option<(UpdateState, String)>,
+    function: &str,
+) -> Result<UpdateState, OrbbecError> {
+    let (state, message) = match state {
+        Some((state, _)) if !state.is_error() => return Ok(state),
+        Some(state) => state,
+        None => {
+            let err_data = OrbbecErrorData {
+                message: "Update finished without reporting a state".to_string(),
+                function: function.to_string(),
+                args: String::new(),
+            };
+
+            return Err(OrbbecError::Unknown(err_data));
+        }
+    };
+
+    let err_data = OrbbecErrorData {
+        message: format!("Update failed ({state:?}): {message}"),
+        function: function.to_string(),
+        args: String::new(),
+    };
+
+    Err(match state {
+        UpdateState::ErrUnsupportDev => OrbbecError::UnsupportedOperation(err_data),
+        UpdateState::ErrMismatch | UpdateState::ErrImageSize | UpdateState::ErrInvalidCount => {
+            OrbbecError::InvalidValue(err_data)
+        }
+        _ => OrbbecError::Unknown(err_data),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_parse() {
+        let version = FirmwareVersion::parse("v1.4.60").unwrap();
+        assert_eq!(version.parts(), [1, 4, 60]);
+        assert_eq!(version.to_string(), "1.4.60");
+
+        assert!(FirmwareVersion::parse("release").is_none());
+        assert!(FirmwareVersion::parse("12").is_none());
+
+        let version =
+            FirmwareVersion::from_file_name(Path::new("/tmp/Gemini330_2.0_Firmware_v1.4.60.bin"))
+                .unwrap();
+        assert_eq!(version.parts(), [1, 4, 60]);
+    }
+
+    #[test]
+    fn test_version_order() {
+        let v = |s| FirmwareVersion::parse(s).unwrap();
+        assert!(v("1.4.60") > v("1.4.9"));
+        assert!(v("1.10.0") > v("1.9.99"));
+        assert_eq!(v("1.4").cmp(&v("1.4.0")), Ordering::Equal);
+
+        let check = FirmwareCheck {
+            device_version: v("1.4.9"),
+            image_version: v("1.4.60"),
+        };
+        assert!(check.is_upgrade());
+        assert!(!check.is_downgrade());
+    }
+}
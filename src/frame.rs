@@ -1,12 +1,136 @@
 //! Frame module
-use crate::{Format, error::OrbbecError, sys::frame::OBFrame};
+use crate::{
+    Format, MetadataType,
+    error::{OrbbecError, OrbbecErrorData},
+    sys::{
+        frame::OBFrame,
+        orb::{OBAccelValue, OBFrameType},
+    },
+};
 
 /// Frame trait
-pub trait Frame: From<OBFrame> + AsRef<OBFrame> {}
+pub trait Frame: From<OBFrame> + AsRef<OBFrame> {
+    /// SDK frame types that can be wrapped by this frame type
+    #[doc(hidden)]
+    const FRAME_TYPES: &'static [OBFrameType];
+}
+
+/// Check that a frame can be wrapped by the frame type `F`
+///
+/// Accessors of the frame types unwrap SDK errors that are only returned for frames
+/// of another type, so a frame must be checked before being wrapped.
+pub(crate) fn check_frame_type<F: Frame>(
+    frame_type: OBFrameType,
+    function: &str,
+) -> Result<(), OrbbecError> {
+    if F::FRAME_TYPES.contains(&frame_type) {
+        return Ok(());
+    }
+
+    let err_data = OrbbecErrorData {
+        message: format!(
+            "{frame_type:?} frame can't be used as {}",
+            std::any::type_name::<F>()
+        ),
+        function: function.to_string(),
+        args: String::new(),
+    };
+
+    Err(OrbbecError::InvalidData(err_data))
+}
+
+/// Every metadata type known by the SDK
+const ALL_METADATA_TYPES: [MetadataType; 34] = [
+    MetadataType::Timestamp,
+    MetadataType::SensorTimestamp,
+    MetadataType::FrameNumber,
+    MetadataType::AutoExposure,
+    MetadataType::Exposure,
+    MetadataType::Gain,
+    MetadataType::AutoWhiteBalance,
+    MetadataType::WhiteBalance,
+    MetadataType::Brightness,
+    MetadataType::Contrast,
+    MetadataType::Saturation,
+    MetadataType::Sharpness,
+    MetadataType::BacklightCompensation,
+    MetadataType::Hue,
+    MetadataType::Gamma,
+    MetadataType::PowerLineFrequency,
+    MetadataType::LowLightCompensation,
+    MetadataType::ManualWhiteBalance,
+    MetadataType::ActualFrameRate,
+    MetadataType::FrameRate,
+    MetadataType::AeRoiLeft,
+    MetadataType::AeRoiTop,
+    MetadataType::AeRoiRight,
+    MetadataType::AeRoiBottom,
+    MetadataType::ExposurePriority,
+    MetadataType::HdrSequenceName,
+    MetadataType::HdrSequenceSize,
+    MetadataType::HdrSequenceIndex,
+    MetadataType::LaserPower,
+    MetadataType::LaserPowerLevel,
+    MetadataType::LaserStatus,
+    MetadataType::GpioInputData,
+    MetadataType::DisparitySearchOffset,
+    MetadataType::DisparitySearchRange,
+];
+
+/// Snapshot of every metadata value carried by a frame
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FrameMetadata {
+    values: Vec<(MetadataType, i64)>,
+}
+
+impl FrameMetadata {
+    fn from_frame(frame: &OBFrame) -> Result<Self, OrbbecError> {
+        let mut values = Vec::new();
+
+        for metadata_type in ALL_METADATA_TYPES {
+            if frame
+                .has_metadata(metadata_type)
+                .map_err(OrbbecError::from)?
+            {
+                let value = frame
+                    .get_metadata_value(metadata_type)
+                    .map_err(OrbbecError::from)?;
+                values.push((metadata_type, value));
+            }
+        }
+
+        Ok(FrameMetadata { values })
+    }
+
+    /// Get the value of the specified metadata, if the frame had it
+    /// ### Arguments
+    /// * `metadata_type` - The metadata to get
+    pub fn get(&self, metadata_type: MetadataType) -> Option<i64> {
+        self.values
+            .iter()
+            .find(|(t, _)| *t == metadata_type)
+            .map(|(_, value)| *value)
+    }
+
+    /// Get the number of metadata values in the snapshot
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if the snapshot is empty
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Get an iterator over the metadata types and values in the snapshot
+    pub fn iter(&self) -> impl Iterator<Item = (MetadataType, i64)> + '_ {
+        self.values.iter().copied()
+    }
+}
 
 /// Video Frame Implementation Macro
 macro_rules! impl_video_frame {
-    ($t:ident) => {
+    ($t:ident, $($frame_type:ident),+) => {
         impl $t {
             /// Get the device timestamp of the video frame
             pub fn timestamp_us(&self) -> u64 {
@@ -58,6 +182,39 @@ macro_rules! impl_video_frame {
                 // Ref: https://github.com/orbbec/OrbbecSDK_v2/blob/815ae047cc977a1f7edd2b97b69ff6cd29f510b3/src/impl/Frame.cpp#L147
                 self.inner.get_format().unwrap()
             }
+
+            /// Get the value of the specified metadata, or `None` if the frame does not carry it
+            /// ### Arguments
+            /// * `metadata_type` - The metadata to get
+            pub fn metadata(
+                &self,
+                metadata_type: MetadataType,
+            ) -> Result<Option<i64>, OrbbecError> {
+                if !self
+                    .inner
+                    .has_metadata(metadata_type)
+                    .map_err(OrbbecError::from)?
+                {
+                    return Ok(None);
+                }
+
+                self.inner
+                    .get_metadata_value(metadata_type)
+                    .map(Some)
+                    .map_err(OrbbecError::from)
+            }
+
+            /// Get every metadata value carried by the video frame
+            pub fn metadata_snapshot(&self) -> Result<FrameMetadata, OrbbecError> {
+                FrameMetadata::from_frame(&self.inner)
+            }
+
+            /// Get the raw metadata buffer of the video frame, as sent by the device
+            pub fn raw_metadata(&self) -> &[u8] {
+                // Unwrap is safe here because internal pointer is guaranteed to be valid
+                // SDK only returns error for this function if pointer is NULL
+                self.inner.get_metadata().unwrap()
+            }
         }
 
         impl From<OBFrame> for $t {
@@ -72,7 +229,9 @@ macro_rules! impl_video_frame {
             }
         }
 
-        impl Frame for $t {}
+        impl Frame for $t {
+            const FRAME_TYPES: &'static [OBFrameType] = &[$(OBFrameType::$frame_type),+];
+        }
     };
 }
 
@@ -80,13 +239,21 @@ macro_rules! impl_video_frame {
 pub struct ColorFrame {
     inner: OBFrame,
 }
-impl_video_frame!(ColorFrame);
+impl_video_frame!(ColorFrame, Color, ColorLeft, ColorRight);
+
+/// Infrared frame
+///
+/// Used for the single IR sensor as well as the left and right IR sensors of stereo cameras.
+pub struct IrFrame {
+    inner: OBFrame,
+}
+impl_video_frame!(IrFrame, Ir, IrLeft, IrRight);
 
 /// Depth frame
 pub struct DepthFrame {
     inner: OBFrame,
 }
-impl_video_frame!(DepthFrame);
+impl_video_frame!(DepthFrame, Depth);
 
 impl DepthFrame {
     /// Get the scale (pixel value * scale = distance in millimeters) of the depth frame
@@ -159,7 +326,98 @@ impl AsRef<OBFrame> for PointCloudFrame {
     }
 }
 
-impl Frame for PointCloudFrame {}
+impl Frame for PointCloudFrame {
+    const FRAME_TYPES: &'static [OBFrameType] = &[OBFrameType::Points];
+}
+
+/// A three-axis IMU sample
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImuValue {
+    /// X-axis component
+    pub x: f32,
+    /// Y-axis component
+    pub y: f32,
+    /// Z-axis component
+    pub z: f32,
+}
+
+impl From<OBAccelValue> for ImuValue {
+    fn from(ob: OBAccelValue) -> Self {
+        ImuValue {
+            x: { ob.x },
+            y: { ob.y },
+            z: { ob.z },
+        }
+    }
+}
+
+/// IMU Frame Implementation Macro
+macro_rules! impl_imu_frame {
+    ($t:ident, $frame_type:ident, $get_value:ident, $get_temperature:ident) => {
+        impl $t {
+            /// Get the device timestamp of the IMU frame
+            pub fn timestamp_us(&self) -> u64 {
+                self.inner.get_timestamp_us().unwrap()
+            }
+
+            /// Get the system timestamp of the IMU frame
+            pub fn system_timestamp_us(&self) -> Result<u64, OrbbecError> {
+                self.inner
+                    .get_system_timestamp_us()
+                    .map_err(OrbbecError::from)
+            }
+
+            /// Get the global timestamp of the IMU frame
+            pub fn global_timestamp_us(&self) -> Result<u64, OrbbecError> {
+                self.inner
+                    .get_global_timestamp_us()
+                    .map_err(OrbbecError::from)
+            }
+
+            /// Get the IMU sample of the frame
+            pub fn value(&self) -> ImuValue {
+                // Unwrap is safe here because internal pointer is guaranteed to be valid and of the right frame type
+                // SDK only returns error for this function if pointer is NULL or of another frame type
+                self.inner.$get_value().map(ImuValue::from).unwrap()
+            }
+
+            /// Get the IMU temperature (in Celsius) when the frame was acquired
+            pub fn temperature(&self) -> f32 {
+                // Unwrap is safe here because internal pointer is guaranteed to be valid and of the right frame type
+                // SDK only returns error for this function if pointer is NULL or of another frame type
+                self.inner.$get_temperature().unwrap()
+            }
+        }
+
+        impl From<OBFrame> for $t {
+            fn from(frame: OBFrame) -> Self {
+                $t { inner: frame }
+            }
+        }
+
+        impl AsRef<OBFrame> for $t {
+            fn as_ref(&self) -> &OBFrame {
+                &self.inner
+            }
+        }
+
+        impl Frame for $t {
+            const FRAME_TYPES: &'static [OBFrameType] = &[OBFrameType::$frame_type];
+        }
+    };
+}
+
+/// Accelerometer frame
+pub struct AccelFrame {
+    inner: OBFrame,
+}
+impl_imu_frame!(AccelFrame, Accel, get_accel_value, get_accel_temperature);
+
+/// Gyroscope frame
+pub struct GyroFrame {
+    inner: OBFrame,
+}
+impl_imu_frame!(GyroFrame, Gyro, get_gyro_value, get_gyro_temperature);
 
 /// A container of multiple frames.
 pub struct FrameSet {
@@ -182,6 +440,46 @@ impl FrameSet {
             .map_err(OrbbecError::from)
             .map(|frame| frame.map(ColorFrame::from))
     }
+
+    /// Get the infrared frame from the frameset
+    pub fn get_ir_frame(&self) -> Result<Option<IrFrame>, OrbbecError> {
+        self.inner
+            .get_ir_frame()
+            .map_err(OrbbecError::from)
+            .map(|frame| frame.map(IrFrame::from))
+    }
+
+    /// Get the left infrared frame from the frameset (stereo cameras)
+    pub fn get_left_ir_frame(&self) -> Result<Option<IrFrame>, OrbbecError> {
+        self.inner
+            .get_frame(OBFrameType::IrLeft)
+            .map_err(OrbbecError::from)
+            .map(|frame| frame.map(IrFrame::from))
+    }
+
+    /// Get the right infrared frame from the frameset (stereo cameras)
+    pub fn get_right_ir_frame(&self) -> Result<Option<IrFrame>, OrbbecError> {
+        self.inner
+            .get_frame(OBFrameType::IrRight)
+            .map_err(OrbbecError::from)
+            .map(|frame| frame.map(IrFrame::from))
+    }
+
+    /// Get the accelerometer frame from the frameset
+    pub fn get_accel_frame(&self) -> Result<Option<AccelFrame>, OrbbecError> {
+        self.inner
+            .get_frame(OBFrameType::Accel)
+            .map_err(OrbbecError::from)
+            .map(|frame| frame.map(AccelFrame::from))
+    }
+
+    /// Get the gyroscope frame from the frameset
+    pub fn get_gyro_frame(&self) -> Result<Option<GyroFrame>, OrbbecError> {
+        self.inner
+            .get_frame(OBFrameType::Gyro)
+            .map_err(OrbbecError::from)
+            .map(|frame| frame.map(GyroFrame::from))
+    }
 }
 
 impl From<OBFrame> for FrameSet {
@@ -196,4 +494,19 @@ impl AsRef<OBFrame> for FrameSet {
     }
 }
 
-impl Frame for FrameSet {}
+impl Frame for FrameSet {
+    const FRAME_TYPES: &'static [OBFrameType] = &[OBFrameType::Set];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_frame_type() {
+        assert!(check_frame_type::<DepthFrame>(OBFrameType::Depth, "test").is_ok());
+        assert!(check_frame_type::<IrFrame>(OBFrameType::IrLeft, "test").is_ok());
+        assert!(check_frame_type::<DepthFrame>(OBFrameType::Points, "test").is_err());
+        assert!(check_frame_type::<ColorFrame>(OBFrameType::Depth, "test").is_err());
+    }
+}
@@ -5,13 +5,22 @@ pub mod calibration;
 pub mod device;
 pub mod error;
 pub mod filter;
+pub mod firmware;
 pub mod frame;
 pub mod logger;
+pub mod net;
 pub mod pipeline;
+pub mod record;
+pub mod sensor;
+pub mod snapshot;
 pub mod stream;
+pub mod sync;
 pub(crate) mod sys;
 
-use std::sync::atomic::AtomicBool;
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 use sys::context::OBContext;
 
@@ -32,6 +41,12 @@ pub use crate::sys::orb::OBStreamType as StreamType;
 #[doc(inline)]
 pub use crate::sys::orb::OBPermissionType as PermissionType;
 
+#[doc(inline)]
+pub use crate::sys::orb::OBPropertyID as PropertyId;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBPropertyType as PropertyType;
+
 #[doc(inline)]
 pub use crate::sys::orb::OBHoleFillingMode as HoleFillMode;
 
@@ -44,6 +59,12 @@ pub use crate::sys::orb::OBCoordinateSystemType as CoordinateSystem;
 #[doc(inline)]
 pub use crate::sys::orb::OBAlignMode as AlignMode;
 
+#[doc(inline)]
+pub use crate::sys::orb::OBFilterConfigValueType as FilterConfigValueType;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBEdgeNoiseRemovalType as EdgeNoiseRemovalType;
+
 #[doc(inline)]
 pub use crate::calibration::{CameraDistortion, CameraIntrinsic};
 
@@ -56,6 +77,54 @@ pub use crate::sys::orb::OBFrameAggregateOutputMode as FrameAggregateOutputMode;
 #[doc(inline)]
 pub use crate::sys::orb::OBDeviceLogSeverityLevel as LogSeverity;
 
+#[doc(inline)]
+pub use crate::sys::orb::OBPlaybackStatus as PlaybackStatus;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBAccelFullScaleRange as AccelFullScaleRange;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBGyroFullScaleRange as GyroFullScaleRange;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBAccelSampleRate as AccelSampleRate;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBGyroSampleRate as GyroSampleRate;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBMultiDeviceSyncMode as MultiDeviceSyncMode;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBIpSourceType as IpSourceType;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBGvcpPortScheme as GvcpPortScheme;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBFrameMetadataType as MetadataType;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBDepthWorkModeTag as DepthWorkModeTag;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBFwUpdateState as UpdateState;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBDeviceState as DeviceState;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBRegionOfInterest as RegionOfInterest;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBHdrConfig as HdrConfig;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBTofExposureThresholdControl as TofExposureThresholdControl;
+
+#[doc(inline)]
+pub use crate::sys::orb::OBNetIpConfigV2 as NetIpConfigV2;
+
 /// There can only be a single context at a time
 /// C API does not enforce this, but having multiple contexts
 /// will lead to crashes and undefined behavior
@@ -70,12 +139,7 @@ impl Context {
     /// Create a new context
     pub fn new() -> Result<Self, error::OrbbecError> {
         if CONTEXT_CREATED
-            .compare_exchange(
-                false,
-                true,
-                std::sync::atomic::Ordering::SeqCst,
-                std::sync::atomic::Ordering::SeqCst,
-            )
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
             .is_err()
         {
             let err_data = error::OrbbecErrorData {
@@ -101,10 +165,202 @@ impl Context {
 
         Ok(device::DeviceList::new(list, self))
     }
+
+    /// Register a callback invoked whenever devices are plugged in or unplugged.
+    ///
+    /// The callback receives the list of removed devices and the list of added devices.
+    /// It remains registered for the lifetime of the returned handle.
+    #[must_use = "dropping the handle will unregister the callback"]
+    pub fn on_devices_changed<F>(
+        &self,
+        callback: F,
+    ) -> Result<device::DevicesChangedCallbackHandle<'_>, error::OrbbecError>
+    where
+        F: FnMut(device::DeviceList<'_>, device::DeviceList<'_>) + Send + 'static,
+    {
+        let data = Box::new(device::DevicesChangedData {
+            context: self as *const Context,
+            callback: Box::new(callback),
+        });
+        let user_data = data.as_ref() as *const _ as *mut std::ffi::c_void;
+
+        let callback_id = self
+            .inner
+            .register_device_changed_callback(Some(device::devices_changed_trampoline), user_data)
+            .map_err(error::OrbbecError::from)?;
+
+        Ok(device::DevicesChangedCallbackHandle::new(
+            self,
+            callback_id,
+            data,
+        ))
+    }
+
+    /// Wait for a device to reconnect after a reboot, then open it
+    ///
+    /// Meant to be called after [`device::Device::reboot`]. If the device is still listed,
+    /// it is first waited to disconnect, so that the instance about to go away is not opened.
+    /// The device list is then polled until the device reappears and can be opened.
+    /// ### Arguments
+    /// * `serial_number` - The serial number of the device (see [`device::DeviceInfo::serial_number`])
+    /// * `timeout` - How long to wait for the device
+    pub fn wait_for_device(
+        &self,
+        serial_number: &str,
+        timeout: Duration,
+    ) -> Result<device::Device, error::OrbbecError> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+        let deadline = Instant::now() + timeout;
+        let cstr = error::to_cstring(serial_number, "serial number", "Context::wait_for_device")?;
+        let timed_out = |state: &str, err: Option<error::OrbbecError>| {
+            let reason = err.map(|e| format!(": {e}")).unwrap_or_default();
+            let err_data = error::OrbbecErrorData {
+                message: format!(
+                    "Timed out after {timeout:?} waiting for device {serial_number} to {state}{reason}"
+                ),
+                function: "Context::wait_for_device".to_string(),
+                args: serial_number.to_string(),
+            };
+
+            error::OrbbecError::DeviceUnavailable(err_data)
+        };
+        let sleep = || {
+            std::thread::sleep(
+                POLL_INTERVAL.min(deadline.saturating_duration_since(Instant::now())),
+            )
+        };
+
+        // The device may disconnect and reconnect between two polls, so watch removals as well
+        let removed = Arc::new(AtomicBool::new(false));
+        let _handle = {
+            let removed = removed.clone();
+            let serial_number = cstr.clone();
+            self.on_devices_changed(move |removed_devices, _| {
+                if removed_devices
+                    .contains_serial(&serial_number)
+                    .unwrap_or(false)
+                {
+                    removed.store(true, Ordering::SeqCst);
+                }
+            })?
+        };
+
+        // Wait for the device to disconnect
+        let mut last_err = None;
+        while !removed.load(Ordering::SeqCst) {
+            match self
+                .query_device_list()
+                .and_then(|devices| devices.contains_serial(&cstr))
+            {
+                Ok(false) => break,
+                Ok(true) => {}
+                Err(err) => last_err = Some(err),
+            }
+
+            if Instant::now() >= deadline {
+                return Err(timed_out("disconnect", last_err));
+            }
+            sleep();
+        }
+
+        // Wait for the device to reconnect, it may be listed before it can be opened
+        loop {
+            let err = match self
+                .query_device_list()
+                .and_then(|devices| devices.get_by_serial_cstr(&cstr))
+            {
+                Ok(device) => return Ok(device),
+                Err(err) => err,
+            };
+
+            if Instant::now() >= deadline {
+                return Err(timed_out("reconnect", Some(err)));
+            }
+            sleep();
+        }
+    }
+
+    /// Enable or disable network device enumeration
+    ///
+    /// When enabled, network devices are discovered through GVCP and returned by [`Context::query_device_list`].
+    /// Devices outside the host subnet are discovered but cannot be opened.
+    /// ### Arguments
+    /// * `enable` - Whether to enumerate network devices
+    pub fn enable_net_device_enumeration(&self, enable: bool) -> Result<(), error::OrbbecError> {
+        self.inner
+            .enable_net_device_enumeration(enable)
+            .map_err(error::OrbbecError::from)
+    }
+
+    /// Open a network device directly from its IP address
+    /// ### Arguments
+    /// * `address` - IP address of the device
+    /// * `port` - Port of the device (usually 8090)
+    pub fn open_net_device(
+        &self,
+        address: Ipv4Addr,
+        port: u16,
+    ) -> Result<device::Device, error::OrbbecError> {
+        // Unwrap is safe here because a formatted IP address never contains a NUL byte
+        let cstr = std::ffi::CString::new(address.to_string()).unwrap();
+
+        self.inner
+            .create_net_device(&cstr, port)
+            .map(device::Device::new)
+            .map_err(error::OrbbecError::from)
+    }
+
+    /// Force an IP configuration on a network device
+    ///
+    /// Works even if the device is not in the host subnet, which allows recovering misconfigured devices.
+    /// ### Arguments
+    /// * `mac_address` - MAC address of the device, in the `xx:xx:xx:xx:xx:xx` format (the UID of a network device)
+    /// * `config` - The new IP configuration
+    pub fn force_ip_config(
+        &self,
+        mac_address: &str,
+        config: &net::NetIpConfig,
+    ) -> Result<(), error::OrbbecError> {
+        let cstr = error::to_cstring(mac_address, "MAC address", "Context::force_ip_config")?;
+
+        let accepted = sys::context::force_ip_config(&cstr, (*config).into())
+            .map_err(error::OrbbecError::from)?;
+
+        if !accepted {
+            let err_data = error::OrbbecErrorData {
+                message: "The device did not accept the IP configuration".to_string(),
+                function: "Context::force_ip_config".to_string(),
+                args: mac_address.to_string(),
+            };
+
+            return Err(error::OrbbecError::DeviceUnavailable(err_data));
+        }
+
+        Ok(())
+    }
+
+    /// Set the GVCP port scheme used for network device discovery and control
+    ///
+    /// Switching to a different scheme disconnects all connected network devices.
+    /// ### Arguments
+    /// * `scheme` - The GVCP port scheme to use
+    pub fn set_gvcp_port_scheme(&self, scheme: GvcpPortScheme) -> Result<(), error::OrbbecError> {
+        self.inner
+            .set_gvcp_port_scheme(scheme)
+            .map_err(error::OrbbecError::from)
+    }
+
+    /// Get the current GVCP port scheme
+    pub fn gvcp_port_scheme(&self) -> Result<GvcpPortScheme, error::OrbbecError> {
+        self.inner
+            .get_gvcp_port_scheme()
+            .map_err(error::OrbbecError::from)
+    }
 }
 
 impl Drop for Context {
     fn drop(&mut self) {
-        CONTEXT_CREATED.store(false, std::sync::atomic::Ordering::SeqCst);
+        CONTEXT_CREATED.store(false, Ordering::SeqCst);
     }
 }
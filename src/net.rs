@@ -0,0 +1,55 @@
+//! Network device module
+use std::net::Ipv4Addr;
+
+use crate::sys::orb::OBNetIpConfig;
+
+/// IPv4 configuration of a network device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetIpConfig {
+    /// Whether the device gets its address through DHCP
+    pub dhcp: bool,
+    /// IP address of the device
+    pub address: Ipv4Addr,
+    /// Subnet mask of the device
+    pub mask: Ipv4Addr,
+    /// Gateway of the device
+    pub gateway: Ipv4Addr,
+}
+
+impl NetIpConfig {
+    /// Create a static IP configuration
+    /// ### Arguments
+    /// * `address` - IP address of the device
+    /// * `mask` - Subnet mask of the device
+    /// * `gateway` - Gateway of the device
+    pub fn new_static(address: Ipv4Addr, mask: Ipv4Addr, gateway: Ipv4Addr) -> Self {
+        NetIpConfig {
+            dhcp: false,
+            address,
+            mask,
+            gateway,
+        }
+    }
+}
+
+impl From<OBNetIpConfig> for NetIpConfig {
+    fn from(ob: OBNetIpConfig) -> Self {
+        NetIpConfig {
+            dhcp: { ob.dhcp } != 0,
+            address: Ipv4Addr::from(ob.address),
+            mask: Ipv4Addr::from(ob.mask),
+            gateway: Ipv4Addr::from(ob.gateway),
+        }
+    }
+}
+
+impl From<NetIpConfig> for OBNetIpConfig {
+    fn from(config: NetIpConfig) -> Self {
+        OBNetIpConfig {
+            dhcp: config.dhcp as u16,
+            address: config.address.octets(),
+            mask: config.mask.octets(),
+            gateway: config.gateway.octets(),
+        }
+    }
+}
@@ -2,7 +2,8 @@
 use std::time::Duration;
 
 use crate::{
-    AlignMode, FrameAggregateOutputMode, SensorType,
+    AccelFullScaleRange, AccelSampleRate, AlignMode, FrameAggregateOutputMode, GyroFullScaleRange,
+    GyroSampleRate, SensorType,
     device::Device,
     frame::FrameSet,
     stream::{StreamProfile, StreamProfileList, VideoStreamProfile},
@@ -69,6 +70,34 @@ impl Config {
             .set_frame_aggregate_output_mode(mode)
             .map_err(crate::error::OrbbecError::from)
     }
+
+    /// Enable the accelerometer stream
+    /// ### Arguments
+    /// * `full_scale_range` - Full-scale range of the accelerometer
+    /// * `sample_rate` - Sample rate of the accelerometer
+    pub fn enable_accel(
+        &mut self,
+        full_scale_range: AccelFullScaleRange,
+        sample_rate: AccelSampleRate,
+    ) -> Result<(), crate::error::OrbbecError> {
+        self.inner
+            .enable_accel_stream(full_scale_range, sample_rate)
+            .map_err(crate::error::OrbbecError::from)
+    }
+
+    /// Enable the gyroscope stream
+    /// ### Arguments
+    /// * `full_scale_range` - Full-scale range of the gyroscope
+    /// * `sample_rate` - Sample rate of the gyroscope
+    pub fn enable_gyro(
+        &mut self,
+        full_scale_range: GyroFullScaleRange,
+        sample_rate: GyroSampleRate,
+    ) -> Result<(), crate::error::OrbbecError> {
+        self.inner
+            .enable_gyro_stream(full_scale_range, sample_rate)
+            .map_err(crate::error::OrbbecError::from)
+    }
 }
 
 type PipelineCallback = Box<Box<dyn FnMut(FrameSet) + Send>>;
@@ -103,6 +132,9 @@ impl Pipeline {
     }
 
     /// Get a list of stream profiles supported by the specified sensor
+    ///
+    /// Infrared profiles are available through [`SensorType::Ir`], or
+    /// [`SensorType::IrLeft`] and [`SensorType::IrRight`] on stereo cameras.
     /// ### Arguments
     /// * `sensor` - Sensor type to get the stream profiles for
     pub fn get_stream_profiles(
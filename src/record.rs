@@ -0,0 +1,180 @@
+//! Record and playback module
+use std::ffi::c_void;
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::PlaybackStatus;
+use crate::device::Device;
+use crate::error::OrbbecError;
+use crate::error::path_to_cstring;
+use crate::sys::orb::OBPlaybackStatus;
+use crate::sys::record::{OBRecordDevice, create_playback_device};
+
+/// Records the streams of a running device to a bag file.
+///
+/// Recording starts as soon as the recorder is created and stops when it is dropped.
+pub struct RecordDevice<'a> {
+    inner: OBRecordDevice,
+    /// We hold a reference to the device to ensure it lives as long as the recorder
+    _device: &'a Device,
+}
+
+impl<'a> RecordDevice<'a> {
+    /// Start recording a device to a file
+    /// ### Arguments
+    /// * `device` - The device to record
+    /// * `file_path` - Path of the bag file to record to
+    /// * `compression` - Whether to compress the recorded frames
+    pub fn new(
+        device: &'a Device,
+        file_path: &Path,
+        compression: bool,
+    ) -> Result<Self, OrbbecError> {
+        let c_path = path_to_cstring(file_path, "RecordDevice::new")?;
+        let recorder =
+            OBRecordDevice::new(device.inner(), &c_path, compression).map_err(OrbbecError::from)?;
+
+        Ok(RecordDevice {
+            inner: recorder,
+            _device: device,
+        })
+    }
+
+    /// Pause recording
+    pub fn pause(&mut self) -> Result<(), OrbbecError> {
+        self.inner.pause().map_err(OrbbecError::from)
+    }
+
+    /// Resume recording
+    pub fn resume(&mut self) -> Result<(), OrbbecError> {
+        self.inner.resume().map_err(OrbbecError::from)
+    }
+}
+
+type PlaybackStatusCallback = Box<Box<dyn FnMut(PlaybackStatus) + Send>>;
+
+/// A device that plays back a recorded bag file.
+///
+/// It dereferences to [`Device`], so it can be used anywhere a device is expected
+/// (e.g. [`crate::pipeline::Pipeline::new`]).
+pub struct PlaybackDevice {
+    inner: Device,
+    _callback: Option<PlaybackStatusCallback>,
+}
+
+impl PlaybackDevice {
+    /// Open a recorded bag file for playback
+    /// ### Arguments
+    /// * `file_path` - Path of the bag file to play back
+    pub fn new(file_path: &Path) -> Result<Self, OrbbecError> {
+        let c_path = path_to_cstring(file_path, "PlaybackDevice::new")?;
+        let device = create_playback_device(&c_path).map_err(OrbbecError::from)?;
+
+        Ok(PlaybackDevice {
+            inner: Device::new(device),
+            _callback: None,
+        })
+    }
+
+    /// Pause playback
+    pub fn pause(&mut self) -> Result<(), OrbbecError> {
+        self.inner
+            .inner()
+            .playback_pause()
+            .map_err(OrbbecError::from)
+    }
+
+    /// Resume playback
+    pub fn resume(&mut self) -> Result<(), OrbbecError> {
+        self.inner
+            .inner()
+            .playback_resume()
+            .map_err(OrbbecError::from)
+    }
+
+    /// Seek to the specified position of the recording
+    /// ### Arguments
+    /// * `position` - Position to seek to, relative to the start of the recording
+    pub fn seek(&mut self, position: Duration) -> Result<(), OrbbecError> {
+        self.inner
+            .inner()
+            .playback_seek(position.as_millis() as u64)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Set the playback rate
+    /// ### Arguments
+    /// * `rate` - Playback rate, where `1.0` is real time
+    pub fn set_rate(&mut self, rate: f32) -> Result<(), OrbbecError> {
+        self.inner
+            .inner()
+            .playback_set_rate(rate)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the current playback status
+    pub fn status(&self) -> Result<PlaybackStatus, OrbbecError> {
+        self.inner
+            .inner()
+            .playback_get_status()
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the current playback position
+    pub fn position(&self) -> Result<Duration, OrbbecError> {
+        self.inner
+            .inner()
+            .playback_get_position()
+            .map(Duration::from_millis)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the total duration of the recording
+    pub fn duration(&self) -> Result<Duration, OrbbecError> {
+        self.inner
+            .inner()
+            .playback_get_duration()
+            .map(Duration::from_millis)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Set a callback invoked whenever the playback status changes.
+    /// It replaces any previously set callback.
+    /// ### Arguments
+    /// * `callback` - Called on each status change; must be `Send + 'static`
+    pub fn set_status_callback<F>(&mut self, callback: F) -> Result<(), OrbbecError>
+    where
+        F: FnMut(PlaybackStatus) + Send + 'static,
+    {
+        let boxed: PlaybackStatusCallback = Box::new(Box::new(callback));
+        let user_data = boxed.as_ref() as *const _ as *mut c_void;
+
+        self.inner
+            .inner()
+            .playback_set_status_callback(Some(playback_status_trampoline), user_data)
+            .map_err(OrbbecError::from)?;
+
+        self._callback = Some(boxed);
+        Ok(())
+    }
+}
+
+impl Deref for PlaybackDevice {
+    type Target = Device;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for PlaybackDevice {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+unsafe extern "C" fn playback_status_trampoline(status: OBPlaybackStatus, user_data: *mut c_void) {
+    let callback = unsafe { &mut *(user_data as *mut Box<dyn FnMut(PlaybackStatus) + Send>) };
+    callback(status);
+}
@@ -0,0 +1,241 @@
+//! Sensor module
+use std::marker::PhantomData;
+
+use crate::{
+    SensorType, StreamType,
+    device::Device,
+    error::OrbbecError,
+    filter::FilterChain,
+    frame::{Frame, check_frame_type},
+    stream::{StreamProfile, StreamProfileList},
+    sys::{
+        frame::OBFrame,
+        orb::OBFrameType,
+        sensor::{OBSensor, OBSensorList, frame_trampoline},
+    },
+};
+
+type SensorCallback = Box<Box<dyn FnMut(OBFrame) + Send>>;
+
+/// Get the type of the frames produced by a stream
+fn stream_frame_type(stream_type: StreamType) -> OBFrameType {
+    match stream_type {
+        StreamType::Unknown => OBFrameType::Unknown,
+        StreamType::Video => OBFrameType::Video,
+        StreamType::Ir => OBFrameType::Ir,
+        StreamType::Color => OBFrameType::Color,
+        StreamType::Depth => OBFrameType::Depth,
+        StreamType::Accel => OBFrameType::Accel,
+        StreamType::Gyro => OBFrameType::Gyro,
+        StreamType::IrLeft => OBFrameType::IrLeft,
+        StreamType::IrRight => OBFrameType::IrRight,
+        StreamType::RawPhase => OBFrameType::RawPhase,
+        StreamType::Confidence => OBFrameType::Confidence,
+        StreamType::Lidar => OBFrameType::LidarPoints,
+        StreamType::ColorLeft => OBFrameType::ColorLeft,
+        StreamType::ColorRight => OBFrameType::ColorRight,
+    }
+}
+
+/// A single sensor of a device
+///
+/// Streaming a sensor directly bypasses the pipeline frame aggregation,
+/// each frame is delivered to the callback as soon as it arrives.
+pub struct Sensor<'a> {
+    inner: OBSensor,
+    _callback: Option<SensorCallback>,
+    _device: PhantomData<&'a Device>,
+}
+
+impl<'a> Sensor<'a> {
+    pub(crate) fn new(inner: OBSensor) -> Self {
+        Sensor {
+            inner,
+            _callback: None,
+            _device: PhantomData,
+        }
+    }
+
+    /// Get the type of the sensor
+    pub fn sensor_type(&self) -> Result<SensorType, OrbbecError> {
+        self.inner.get_type().map_err(OrbbecError::from)
+    }
+
+    /// Get the list of stream profiles supported by the sensor
+    pub fn get_stream_profiles(&self) -> Result<StreamProfileList, OrbbecError> {
+        self.inner
+            .get_stream_profile_list()
+            .map(StreamProfileList::new)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the post-processing filters recommended by the SDK for the sensor
+    ///
+    /// The filters come with parameters tuned for the device, some of them may be disabled by default.
+    pub fn recommended_filters(&self) -> Result<FilterChain, OrbbecError> {
+        let list = self
+            .inner
+            .create_recommended_filter_list()
+            .map_err(OrbbecError::from)?;
+
+        FilterChain::from_list(list)
+    }
+
+    /// Start the sensor with the given profile, invoking a callback for each frame
+    ///
+    /// ### Type Parameters
+    /// * `F` - The frame type produced by the sensor (e.g. [`crate::frame::IrFrame`]).
+    ///   An error is returned if the stream of the profile produces another frame type.
+    ///
+    /// ### Arguments
+    /// * `profile` - Stream profile to start the sensor with
+    /// * `callback` - Called on each incoming frame; must be `Send + 'static`
+    pub fn start<F, S, C>(&mut self, profile: &S, mut callback: C) -> Result<(), OrbbecError>
+    where
+        F: Frame,
+        S: StreamProfile,
+        C: FnMut(F) + Send + 'static,
+    {
+        let stream_type = profile.as_ref().get_type().map_err(OrbbecError::from)?;
+        check_frame_type::<F>(stream_frame_type(stream_type), "Sensor::start")?;
+
+        let boxed: SensorCallback = Box::new(Box::new(move |frame| callback(F::from(frame))));
+        let user_data = boxed.as_ref() as *const _ as *mut std::ffi::c_void;
+
+        self.inner
+            .start(profile.as_ref(), Some(frame_trampoline), user_data)
+            .map_err(OrbbecError::from)?;
+
+        self._callback = Some(boxed);
+        Ok(())
+    }
+
+    /// Stop the sensor
+    pub fn stop(&mut self) -> Result<(), OrbbecError> {
+        // The SDK may still invoke the callback if the sensor failed to stop, so keep it alive
+        self.inner.stop().map_err(OrbbecError::from)?;
+        self._callback = None;
+        Ok(())
+    }
+
+    /// Switch the stream profile of a running sensor
+    /// ### Arguments
+    /// * `profile` - Stream profile to switch to
+    pub fn switch_profile<S: StreamProfile>(&mut self, profile: &S) -> Result<(), OrbbecError> {
+        self.inner
+            .switch_profile(profile.as_ref())
+            .map_err(OrbbecError::from)
+    }
+}
+
+impl Drop for Sensor<'_> {
+    fn drop(&mut self) {
+        // The SDK keeps streaming after the sensor handle is deleted,
+        // so stop it before the callback is freed
+        if self._callback.is_some() && self.inner.stop().is_err() {
+            // The SDK may still invoke the callback, leak it rather than freeing it
+            std::mem::forget(self._callback.take());
+        }
+    }
+}
+
+/// A list of the sensors of a device
+pub struct SensorList<'a> {
+    inner: OBSensorList,
+    _device: PhantomData<&'a Device>,
+}
+
+impl<'a> SensorList<'a> {
+    pub(crate) fn new(inner: OBSensorList) -> Self {
+        SensorList {
+            inner,
+            _device: PhantomData,
+        }
+    }
+
+    /// Get the number of sensors in the list
+    pub fn len(&self) -> usize {
+        // Unwrap is safe here because internal pointer is guaranteed to be valid
+        // SDK only returns error for this function if pointer is NULL
+        self.inner.get_count().unwrap() as usize
+    }
+
+    /// Check if the sensor list is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the type of the sensor at the specified index
+    /// ### Arguments
+    /// * `index` - The index of the sensor
+    pub fn sensor_type(&self, index: usize) -> Result<SensorType, OrbbecError> {
+        self.inner
+            .get_sensor_type(index as u32)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the sensor at the specified index
+    /// ### Arguments
+    /// * `index` - The index of the sensor to get
+    pub fn get(&self, index: usize) -> Result<Sensor<'a>, OrbbecError> {
+        self.inner
+            .get_sensor(index as u32)
+            .map(Sensor::new)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the sensor of the specified type, if the device has one
+    /// ### Arguments
+    /// * `sensor_type` - The type of the sensor to get
+    pub fn get_by_type(&self, sensor_type: SensorType) -> Result<Option<Sensor<'a>>, OrbbecError> {
+        self.inner
+            .get_sensor_by_type(sensor_type)
+            .map(|sensor| sensor.map(Sensor::new))
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get an iterator over the sensors in the list.
+    pub fn iter(&self) -> SensorListIterator<'a, '_> {
+        SensorListIterator::new(self)
+    }
+}
+
+/// An iterator over the sensors in a sensor list
+pub struct SensorListIterator<'a, 'b> {
+    sensor_list: &'b SensorList<'a>,
+    index: usize,
+    count: usize,
+}
+
+impl<'a, 'b> SensorListIterator<'a, 'b> {
+    fn new(sensor_list: &'b SensorList<'a>) -> Self {
+        SensorListIterator {
+            sensor_list,
+            index: 0,
+            count: sensor_list.len(),
+        }
+    }
+}
+
+impl<'a, 'b> Iterator for SensorListIterator<'a, 'b> {
+    type Item = Result<Sensor<'a>, OrbbecError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let sensor = self.sensor_list.get(self.index);
+        self.index += 1;
+        Some(sensor)
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b SensorList<'a> {
+    type Item = Result<Sensor<'a>, OrbbecError>;
+    type IntoIter = SensorListIterator<'a, 'b>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
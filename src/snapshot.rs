@@ -0,0 +1,236 @@
+//! Device settings snapshot module
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::prop::PropertyValue;
+
+/// Value of a single property in a [`PropertySnapshot`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SnapshotValue {
+    /// Value of a bool property
+    Bool(bool),
+    /// Value of an int property
+    Int(i32),
+    /// Value of a float property
+    Float(f32),
+    /// Raw bytes of a struct property, as reported by the device
+    Struct(Vec<u8>),
+}
+
+impl From<PropertyValue> for SnapshotValue {
+    fn from(value: PropertyValue) -> Self {
+        match value {
+            PropertyValue::Bool(v) => SnapshotValue::Bool(v),
+            PropertyValue::Int(v) => SnapshotValue::Int(v),
+            PropertyValue::Float(v) => SnapshotValue::Float(v),
+        }
+    }
+}
+
+/// Values of the readable properties of a device, keyed by property name
+///
+/// Created with [`crate::device::Device::snapshot_properties`] and written back with
+/// [`crate::device::Device::apply_snapshot`].
+/// Property names are the same as the typed property structs (e.g. `"ColorExposure"`).
+/// Supported properties that failed to be read are recorded separately,
+/// see [`PropertySnapshot::unreadable`].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropertySnapshot {
+    values: BTreeMap<String, SnapshotValue>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    unreadable: BTreeSet<String>,
+}
+
+impl PropertySnapshot {
+    /// Create an empty snapshot
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the value of a property
+    /// ### Arguments
+    /// * `name` - The name of the property
+    pub fn get(&self, name: &str) -> Option<&SnapshotValue> {
+        self.values.get(name)
+    }
+
+    /// Set the value of a property, returning the previous value
+    /// ### Arguments
+    /// * `name` - The name of the property
+    /// * `value` - The value of the property
+    pub fn insert(&mut self, name: &str, value: SnapshotValue) -> Option<SnapshotValue> {
+        self.unreadable.remove(name);
+        self.values.insert(name.to_string(), value)
+    }
+
+    /// Record a supported property that failed to be read, removing its value
+    /// ### Arguments
+    /// * `name` - The name of the property
+    pub fn insert_unreadable(&mut self, name: &str) -> Option<SnapshotValue> {
+        self.unreadable.insert(name.to_string());
+        self.values.remove(name)
+    }
+
+    /// Get the names of the supported properties that failed to be read, sorted by name
+    pub fn unreadable(&self) -> impl Iterator<Item = &str> {
+        self.unreadable.iter().map(String::as_str)
+    }
+
+    /// Remove a property from the snapshot, returning its value
+    /// ### Arguments
+    /// * `name` - The name of the property
+    pub fn remove(&mut self, name: &str) -> Option<SnapshotValue> {
+        self.values.remove(name)
+    }
+
+    /// Get the number of properties in the snapshot
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if the snapshot is empty
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Get an iterator over the properties in the snapshot, sorted by name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &SnapshotValue)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Compare the snapshot against another one, sorted by property name
+    ///
+    /// Properties present in only one of the snapshots are reported with
+    /// `None` on the other side.
+    /// Properties that failed to be read in either snapshot are not compared.
+    /// ### Arguments
+    /// * `other` - The snapshot to compare against
+    pub fn diff(&self, other: &PropertySnapshot) -> Vec<PropertyChange> {
+        let mut changes: Vec<PropertyChange> = self
+            .values
+            .iter()
+            .filter(|(name, _)| !other.unreadable.contains(*name))
+            .filter(|(name, value)| other.values.get(*name) != Some(value))
+            .map(|(name, value)| PropertyChange {
+                name: name.clone(),
+                before: Some(value.clone()),
+                after: other.values.get(name).cloned(),
+            })
+            .collect();
+
+        changes.extend(
+            other
+                .values
+                .iter()
+                .filter(|(name, _)| {
+                    !self.values.contains_key(*name) && !self.unreadable.contains(*name)
+                })
+                .map(|(name, value)| PropertyChange {
+                    name: name.clone(),
+                    before: None,
+                    after: Some(value.clone()),
+                }),
+        );
+
+        changes.sort_by(|a, b| a.name.cmp(&b.name));
+        changes
+    }
+}
+
+/// Automatic modes and the manual values they override
+///
+/// The device may reject or discard manual values written while the automatic mode is on,
+/// and turning it on overrides them.
+const AUTO_MANUAL_PROPERTIES: &[(&str, &[&str])] = &[
+    ("ColorAutoExposure", &["ColorExposure", "ColorGain"]),
+    ("ColorAutoWhiteBalance", &["ColorWhiteBalance"]),
+    ("DepthAutoExposure", &["DepthExposure", "DepthGain"]),
+    ("IrAutoExposure", &["IrExposure", "IrGain"]),
+];
+
+/// Get the rank of a property in the write order of a snapshot
+///
+/// Automatic modes being turned off are written first (0), automatic modes
+/// being turned on last (2), and every other property in between (1).
+pub(crate) fn write_order(name: &str, value: &SnapshotValue) -> u8 {
+    let is_auto = AUTO_MANUAL_PROPERTIES.iter().any(|(auto, _)| *auto == name);
+
+    match value {
+        SnapshotValue::Bool(false) if is_auto => 0,
+        SnapshotValue::Bool(true) if is_auto => 2,
+        _ => 1,
+    }
+}
+
+/// A property whose value differs between two snapshots
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropertyChange {
+    /// Name of the property
+    pub name: String,
+    /// Value in the original snapshot, if present
+    pub before: Option<SnapshotValue>,
+    /// Value in the compared snapshot, if present
+    pub after: Option<SnapshotValue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let mut a = PropertySnapshot::new();
+        a.insert("ColorAutoExposure", SnapshotValue::Bool(true));
+        a.insert("ColorGain", SnapshotValue::Int(16));
+        a.insert("DepthAeRoi", SnapshotValue::Struct(vec![0, 1, 2]));
+
+        let mut b = a.clone();
+        assert!(a.diff(&b).is_empty());
+
+        b.insert("ColorGain", SnapshotValue::Int(32));
+        b.remove("DepthAeRoi");
+        b.insert("LaserPower", SnapshotValue::Float(1.5));
+
+        let changes = a.diff(&b);
+        let names: Vec<_> = changes.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["ColorGain", "DepthAeRoi", "LaserPower"]);
+        assert_eq!(changes[0].before, Some(SnapshotValue::Int(16)));
+        assert_eq!(changes[0].after, Some(SnapshotValue::Int(32)));
+        assert_eq!(changes[1].after, None);
+        assert_eq!(changes[2].before, None);
+
+        // A property that failed to be read is not reported as removed
+        b.insert_unreadable("ColorGain");
+        let names: Vec<_> = a.diff(&b).into_iter().map(|c| c.name).collect();
+        assert_eq!(names, ["DepthAeRoi", "LaserPower"]);
+        assert_eq!(b.unreadable().collect::<Vec<_>>(), ["ColorGain"]);
+    }
+
+    #[test]
+    fn test_write_order() {
+        let mut snapshot = PropertySnapshot::new();
+        snapshot.insert("ColorExposure", SnapshotValue::Int(100));
+        snapshot.insert("ColorAutoExposure", SnapshotValue::Bool(false));
+        snapshot.insert("DepthAutoExposure", SnapshotValue::Bool(true));
+        snapshot.insert("DepthExposure", SnapshotValue::Int(3000));
+        snapshot.insert("AutoCaptureEnable", SnapshotValue::Bool(false));
+
+        let mut entries: Vec<_> = snapshot.iter().collect();
+        entries.sort_by_key(|(name, value)| write_order(name, value));
+        let names: Vec<_> = entries.iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            names,
+            [
+                "ColorAutoExposure",
+                "AutoCaptureEnable",
+                "ColorExposure",
+                "DepthExposure",
+                "DepthAutoExposure",
+            ]
+        );
+    }
+}
@@ -1,6 +1,8 @@
 //! Stream module
 use crate::{
-    CameraDistortion, CameraIntrinsic, Format,
+    AccelFullScaleRange, AccelSampleRate, CameraDistortion, CameraIntrinsic, Format,
+    GyroFullScaleRange, GyroSampleRate, StreamType,
+    calibration::{AccelIntrinsic, GyroIntrinsic},
     error::OrbbecError,
     sys::stream::{OBStreamProfile, OBStreamProfileList},
 };
@@ -88,6 +90,11 @@ impl VideoStreamProfile {
     pub fn format(&self) -> Result<Format, OrbbecError> {
         self.inner.get_format().map_err(OrbbecError::from)
     }
+
+    /// Get the stream type of this stream profile (e.g. depth, color or one of the IR streams)
+    pub fn stream_type(&self) -> Result<StreamType, OrbbecError> {
+        self.inner.get_type().map_err(OrbbecError::from)
+    }
 }
 
 impl AsRef<OBStreamProfile> for VideoStreamProfile {
@@ -97,6 +104,84 @@ impl AsRef<OBStreamProfile> for VideoStreamProfile {
 }
 impl StreamProfile for VideoStreamProfile {}
 
+/// Accelerometer stream profile
+pub struct AccelStreamProfile {
+    inner: OBStreamProfile,
+}
+
+impl AccelStreamProfile {
+    pub(crate) fn new(inner: OBStreamProfile) -> Self {
+        AccelStreamProfile { inner }
+    }
+
+    /// Get the full-scale range of this accelerometer stream profile
+    pub fn full_scale_range(&self) -> Result<AccelFullScaleRange, OrbbecError> {
+        self.inner
+            .get_accel_full_scale_range()
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the sample rate of this accelerometer stream profile
+    pub fn sample_rate(&self) -> Result<AccelSampleRate, OrbbecError> {
+        self.inner
+            .get_accel_sample_rate()
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the accelerometer intrinsic parameters for this stream profile
+    pub fn get_intrinsic(&self) -> Result<AccelIntrinsic, OrbbecError> {
+        self.inner
+            .get_accel_intrinsic()
+            .map(AccelIntrinsic::from)
+            .map_err(OrbbecError::from)
+    }
+}
+
+impl AsRef<OBStreamProfile> for AccelStreamProfile {
+    fn as_ref(&self) -> &OBStreamProfile {
+        &self.inner
+    }
+}
+impl StreamProfile for AccelStreamProfile {}
+
+/// Gyroscope stream profile
+pub struct GyroStreamProfile {
+    inner: OBStreamProfile,
+}
+
+impl GyroStreamProfile {
+    pub(crate) fn new(inner: OBStreamProfile) -> Self {
+        GyroStreamProfile { inner }
+    }
+
+    /// Get the full-scale range of this gyroscope stream profile
+    pub fn full_scale_range(&self) -> Result<GyroFullScaleRange, OrbbecError> {
+        self.inner
+            .get_gyro_full_scale_range()
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the sample rate of this gyroscope stream profile
+    pub fn sample_rate(&self) -> Result<GyroSampleRate, OrbbecError> {
+        self.inner.get_gyro_sample_rate().map_err(OrbbecError::from)
+    }
+
+    /// Get the gyroscope intrinsic parameters for this stream profile
+    pub fn get_intrinsic(&self) -> Result<GyroIntrinsic, OrbbecError> {
+        self.inner
+            .get_gyro_intrinsic()
+            .map(GyroIntrinsic::from)
+            .map_err(OrbbecError::from)
+    }
+}
+
+impl AsRef<OBStreamProfile> for GyroStreamProfile {
+    fn as_ref(&self) -> &OBStreamProfile {
+        &self.inner
+    }
+}
+impl StreamProfile for GyroStreamProfile {}
+
 /// List of video stream profiles
 pub struct StreamProfileList {
     inner: OBStreamProfileList,
@@ -158,6 +243,36 @@ impl StreamProfileList {
         }
     }
 
+    /// Get the accelerometer stream profile matching the passed parameters.
+    /// ### Arguments
+    /// * `full_scale_range` - Full-scale range of the accelerometer
+    /// * `sample_rate` - Sample rate of the accelerometer
+    pub fn get_accel_stream_profile(
+        &self,
+        full_scale_range: AccelFullScaleRange,
+        sample_rate: AccelSampleRate,
+    ) -> Result<AccelStreamProfile, OrbbecError> {
+        self.inner
+            .get_accel_stream_profile(full_scale_range, sample_rate)
+            .map(AccelStreamProfile::new)
+            .map_err(OrbbecError::from)
+    }
+
+    /// Get the gyroscope stream profile matching the passed parameters.
+    /// ### Arguments
+    /// * `full_scale_range` - Full-scale range of the gyroscope
+    /// * `sample_rate` - Sample rate of the gyroscope
+    pub fn get_gyro_stream_profile(
+        &self,
+        full_scale_range: GyroFullScaleRange,
+        sample_rate: GyroSampleRate,
+    ) -> Result<GyroStreamProfile, OrbbecError> {
+        self.inner
+            .get_gyro_stream_profile(full_scale_range, sample_rate)
+            .map(GyroStreamProfile::new)
+            .map_err(OrbbecError::from)
+    }
+
     /// Render every video stream profile in this list as a human-readable
     /// `WxH @ FPSfps (FORMAT)` line, one per line. Used by
     /// [`Self::get_video_stream_profile`] to enrich its error message and
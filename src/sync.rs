@@ -0,0 +1,273 @@
+//! Multi-device synchronization module
+use crate::MultiDeviceSyncMode;
+use crate::error::{OrbbecError, OrbbecErrorData};
+use crate::sys::orb::OBMultiDeviceSyncConfig;
+
+/// Every sync mode known by the SDK, in bitmap order
+const ALL_SYNC_MODES: [MultiDeviceSyncMode; 9] = [
+    MultiDeviceSyncMode::FreeRun,
+    MultiDeviceSyncMode::Standalone,
+    MultiDeviceSyncMode::Primary,
+    MultiDeviceSyncMode::Secondary,
+    MultiDeviceSyncMode::SecondarySynced,
+    MultiDeviceSyncMode::SoftwareTriggering,
+    MultiDeviceSyncMode::HardwareTriggering,
+    MultiDeviceSyncMode::IrImuSync,
+    MultiDeviceSyncMode::SoftwareSynced,
+];
+
+/// Decode the supported sync mode bitmap reported by the device
+pub(crate) fn sync_modes_from_bitmap(bitmap: u16) -> Vec<MultiDeviceSyncMode> {
+    ALL_SYNC_MODES
+        .into_iter()
+        .filter(|mode| bitmap as u32 & *mode as u32 != 0)
+        .collect()
+}
+
+/// Multi-device synchronization configuration
+///
+/// Use [`MultiDeviceSyncConfig::builder`] to create a validated configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultiDeviceSyncConfig {
+    mode: MultiDeviceSyncMode,
+    depth_delay_us: u32,
+    color_delay_us: u32,
+    trigger_to_image_delay_us: u32,
+    trigger_out_enable: bool,
+    trigger_out_delay_us: u32,
+    frames_per_trigger: u32,
+}
+
+impl MultiDeviceSyncConfig {
+    /// Create a builder for the given sync mode
+    /// ### Arguments
+    /// * `mode` - The sync mode of the device
+    pub fn builder(mode: MultiDeviceSyncMode) -> MultiDeviceSyncConfigBuilder {
+        MultiDeviceSyncConfigBuilder::new(mode)
+    }
+
+    /// Sync mode of the device
+    pub fn mode(&self) -> MultiDeviceSyncMode {
+        self.mode
+    }
+
+    /// Delay (in microseconds) of the depth image capture after receiving the trigger signal
+    pub fn depth_delay_us(&self) -> u32 {
+        self.depth_delay_us
+    }
+
+    /// Delay (in microseconds) of the color image capture after receiving the trigger signal
+    pub fn color_delay_us(&self) -> u32 {
+        self.color_delay_us
+    }
+
+    /// Delay (in microseconds) of the image capture after receiving the trigger signal
+    pub fn trigger_to_image_delay_us(&self) -> u32 {
+        self.trigger_to_image_delay_us
+    }
+
+    /// Whether the trigger signal is output via the VSYNC_OUT pin
+    pub fn trigger_out_enable(&self) -> bool {
+        self.trigger_out_enable
+    }
+
+    /// Delay (in microseconds) of the trigger signal output after receiving the capture command or trigger signal
+    pub fn trigger_out_delay_us(&self) -> u32 {
+        self.trigger_out_delay_us
+    }
+
+    /// Number of frames captured on each trigger (triggering modes only)
+    pub fn frames_per_trigger(&self) -> u32 {
+        self.frames_per_trigger
+    }
+}
+
+impl From<OBMultiDeviceSyncConfig> for MultiDeviceSyncConfig {
+    fn from(ob: OBMultiDeviceSyncConfig) -> Self {
+        MultiDeviceSyncConfig {
+            mode: { ob.sync_mode },
+            depth_delay_us: { ob.depth_delay_us }.max(0) as u32,
+            color_delay_us: { ob.color_delay_us }.max(0) as u32,
+            trigger_to_image_delay_us: { ob.trigger_2_image_delay_us }.max(0) as u32,
+            trigger_out_enable: { ob.trigger_out_enable },
+            trigger_out_delay_us: { ob.trigger_out_delay_us }.max(0) as u32,
+            frames_per_trigger: { ob.frames_per_trigger }.max(0) as u32,
+        }
+    }
+}
+
+impl From<MultiDeviceSyncConfig> for OBMultiDeviceSyncConfig {
+    fn from(c: MultiDeviceSyncConfig) -> Self {
+        // Builder guarantees every value fits in an i32
+        OBMultiDeviceSyncConfig {
+            sync_mode: c.mode,
+            depth_delay_us: c.depth_delay_us as i32,
+            color_delay_us: c.color_delay_us as i32,
+            trigger_2_image_delay_us: c.trigger_to_image_delay_us as i32,
+            trigger_out_enable: c.trigger_out_enable,
+            trigger_out_delay_us: c.trigger_out_delay_us as i32,
+            frames_per_trigger: c.frames_per_trigger as i32,
+        }
+    }
+}
+
+/// Builder for [`MultiDeviceSyncConfig`]
+#[derive(Debug, Clone)]
+pub struct MultiDeviceSyncConfigBuilder {
+    config: MultiDeviceSyncConfig,
+}
+
+impl MultiDeviceSyncConfigBuilder {
+    fn new(mode: MultiDeviceSyncMode) -> Self {
+        MultiDeviceSyncConfigBuilder {
+            config: MultiDeviceSyncConfig {
+                mode,
+                depth_delay_us: 0,
+                color_delay_us: 0,
+                trigger_to_image_delay_us: 0,
+                trigger_out_enable: true,
+                trigger_out_delay_us: 0,
+                frames_per_trigger: 1,
+            },
+        }
+    }
+
+    /// Set the depth image capture delay (in microseconds)
+    pub fn depth_delay_us(mut self, delay: u32) -> Self {
+        self.config.depth_delay_us = delay;
+        self
+    }
+
+    /// Set the color image capture delay (in microseconds)
+    pub fn color_delay_us(mut self, delay: u32) -> Self {
+        self.config.color_delay_us = delay;
+        self
+    }
+
+    /// Set the image capture delay after receiving the trigger signal (in microseconds)
+    pub fn trigger_to_image_delay_us(mut self, delay: u32) -> Self {
+        self.config.trigger_to_image_delay_us = delay;
+        self
+    }
+
+    /// Enable or disable the trigger signal output via the VSYNC_OUT pin (enabled by default)
+    pub fn trigger_out_enable(mut self, enable: bool) -> Self {
+        self.config.trigger_out_enable = enable;
+        self
+    }
+
+    /// Set the trigger signal output delay (in microseconds)
+    pub fn trigger_out_delay_us(mut self, delay: u32) -> Self {
+        self.config.trigger_out_delay_us = delay;
+        self
+    }
+
+    /// Set the number of frames captured on each trigger (defaults to 1)
+    pub fn frames_per_trigger(mut self, frames: u32) -> Self {
+        self.config.frames_per_trigger = frames;
+        self
+    }
+
+    /// Validate and build the configuration
+    pub fn build(self) -> Result<MultiDeviceSyncConfig, OrbbecError> {
+        let c = self.config;
+
+        let values = [
+            ("depth_delay_us", c.depth_delay_us),
+            ("color_delay_us", c.color_delay_us),
+            ("trigger_to_image_delay_us", c.trigger_to_image_delay_us),
+            ("trigger_out_delay_us", c.trigger_out_delay_us),
+            ("frames_per_trigger", c.frames_per_trigger),
+        ];
+        for (name, value) in values {
+            if value > i32::MAX as u32 {
+                return Err(invalid_value(
+                    format!("{name} must be at most {}", i32::MAX),
+                    name,
+                ));
+            }
+        }
+
+        let triggering = matches!(
+            c.mode,
+            MultiDeviceSyncMode::SoftwareTriggering | MultiDeviceSyncMode::HardwareTriggering
+        );
+        if triggering && c.frames_per_trigger == 0 {
+            return Err(invalid_value(
+                format!("frames_per_trigger must be at least 1 in {:?} mode", c.mode),
+                "frames_per_trigger",
+            ));
+        }
+
+        Ok(c)
+    }
+}
+
+fn invalid_value(message: String, args: &str) -> OrbbecError {
+    OrbbecError::InvalidValue(OrbbecErrorData {
+        message,
+        function: "MultiDeviceSyncConfigBuilder::build".to_string(),
+        args: args.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_modes_from_bitmap() {
+        assert!(sync_modes_from_bitmap(0).is_empty());
+
+        let modes = sync_modes_from_bitmap(
+            MultiDeviceSyncMode::Primary as u16
+                | MultiDeviceSyncMode::Secondary as u16
+                | MultiDeviceSyncMode::SoftwareTriggering as u16,
+        );
+        assert_eq!(
+            modes,
+            vec![
+                MultiDeviceSyncMode::Primary,
+                MultiDeviceSyncMode::Secondary,
+                MultiDeviceSyncMode::SoftwareTriggering,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_builder_validation() {
+        let config = MultiDeviceSyncConfig::builder(MultiDeviceSyncMode::SoftwareTriggering)
+            .frames_per_trigger(2)
+            .build()
+            .unwrap();
+        assert_eq!(config.frames_per_trigger(), 2);
+        assert!(config.trigger_out_enable());
+
+        assert!(
+            MultiDeviceSyncConfig::builder(MultiDeviceSyncMode::HardwareTriggering)
+                .frames_per_trigger(0)
+                .build()
+                .is_err()
+        );
+        assert!(
+            MultiDeviceSyncConfig::builder(MultiDeviceSyncMode::Primary)
+                .depth_delay_us(u32::MAX)
+                .build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_roundtrip() {
+        let config = MultiDeviceSyncConfig::builder(MultiDeviceSyncMode::Secondary)
+            .depth_delay_us(10)
+            .color_delay_us(20)
+            .trigger_to_image_delay_us(30)
+            .trigger_out_enable(false)
+            .trigger_out_delay_us(40)
+            .build()
+            .unwrap();
+        let ob = OBMultiDeviceSyncConfig::from(config);
+        assert_eq!(MultiDeviceSyncConfig::from(ob), config);
+    }
+}
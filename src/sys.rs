@@ -9,6 +9,8 @@ pub mod logger;
 pub(crate) mod orb;
 pub mod pipeline;
 pub mod prop;
+pub mod record;
+pub mod sensor;
 pub mod stream;
 
 macro_rules! drop_ob_object {
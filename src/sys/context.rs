@@ -1,7 +1,13 @@
 //! Context management and device enumeration
-use super::device::OBDeviceList;
+use std::ffi::CStr;
+
+use super::device::{OBDevice, OBDeviceList};
+use super::orb::{OBGvcpPortScheme, OBNetIpConfig};
 use super::{OBError, call_ob_function, drop_ob_object, orb};
 
+/// Raw device changed callback type from the C API.
+pub(crate) type OBDeviceChangedCallback = orb::ob_device_changed_callback;
+
 /// Context is a management class that describes the runtime of the SDK and is responsible for resource allocation and release of the SDK.
 /// Context has the ability to manage multiple devices. It is responsible for enumerating devices, monitoring device callbacks, and enabling multi-device synchronization.
 pub struct OBContext {
@@ -22,4 +28,60 @@ impl OBContext {
         let list = call_ob_function!(orb::ob_query_device_list, self.inner)?;
         Ok(OBDeviceList::new(list))
     }
+
+    /// Register a callback triggered whenever a device is plugged in or unplugged.
+    /// Returns the callback ID used to unregister it.
+    pub fn register_device_changed_callback(
+        &self,
+        callback: OBDeviceChangedCallback,
+        user_data: *mut std::ffi::c_void,
+    ) -> Result<u64, OBError> {
+        call_ob_function!(
+            orb::ob_register_device_changed_callback,
+            self.inner,
+            callback,
+            user_data
+        )
+    }
+
+    /// Unregister a previously registered device changed callback
+    pub fn unregister_device_changed_callback(&self, callback_id: u64) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_unregister_device_changed_callback,
+            self.inner,
+            callback_id
+        )
+    }
+
+    /// Enable or disable network device enumeration
+    pub fn enable_net_device_enumeration(&self, enable: bool) -> Result<(), OBError> {
+        call_ob_function!(orb::ob_enable_net_device_enumeration, self.inner, enable)
+    }
+
+    /// Create a network device object from its IP address and port
+    pub fn create_net_device(&self, address: &CStr, port: u16) -> Result<OBDevice, OBError> {
+        let device = call_ob_function!(
+            orb::ob_create_net_device,
+            self.inner,
+            address.as_ptr(),
+            port
+        )?;
+        Ok(OBDevice::new(device))
+    }
+
+    /// Set the GVCP port scheme used for network device discovery and control
+    pub fn set_gvcp_port_scheme(&self, scheme: OBGvcpPortScheme) -> Result<(), OBError> {
+        call_ob_function!(orb::ob_set_gvcp_port_scheme, self.inner, scheme)
+    }
+
+    /// Get the current GVCP port scheme
+    pub fn get_gvcp_port_scheme(&self) -> Result<OBGvcpPortScheme, OBError> {
+        call_ob_function!(orb::ob_get_gvcp_port_scheme, self.inner)
+    }
+}
+
+/// Force a static IP configuration on the network device with the given MAC address.
+/// Returns whether the configuration command was processed successfully.
+pub fn force_ip_config(mac_address: &CStr, config: OBNetIpConfig) -> Result<bool, OBError> {
+    call_ob_function!(orb::ob_force_ip_config, mac_address.as_ptr(), config)
 }
@@ -4,9 +4,89 @@ use std::mem::MaybeUninit;
 
 use crate::prop::StructProperty;
 
-use super::orb::{OBDeviceType, OBPermissionType, OBPropertyID};
+use super::orb::{
+    OBBoolPropertyRange, OBDataTranState, OBDepthWorkMode, OBDeviceState, OBDeviceType,
+    OBErrorCode, OBFloatPropertyRange, OBFwUpdateState, OBIntPropertyRange, OBIpSourceType,
+    OBMultiDeviceSyncConfig, OBPermissionType, OBPropertyID, OBPropertyItem,
+};
+use super::sensor::OBSensorList;
 use super::{OBError, call_ob_function, drop_ob_object, impl_ob_method, orb};
 
+/// Raw firmware update callback type from the C API.
+pub(crate) type OBFwUpdateCallback = orb::ob_device_fw_update_callback;
+
+pub(crate) unsafe extern "C" fn fw_update_trampoline(
+    state: OBFwUpdateState,
+    message: *const std::ffi::c_char,
+    percent: u8,
+    user_data: *mut std::ffi::c_void,
+) {
+    let callback = unsafe { &mut *(user_data as *mut &mut dyn FnMut(OBFwUpdateState, &str, u8)) };
+    let message = if message.is_null() {
+        std::borrow::Cow::Borrowed("")
+    } else {
+        unsafe { CStr::from_ptr(message) }.to_string_lossy()
+    };
+    callback(state, &message, percent);
+}
+
+/// Raw device state callback type from the C API.
+pub(crate) type OBDeviceStateCallback = orb::ob_device_state_callback;
+
+pub(crate) unsafe extern "C" fn state_changed_trampoline(
+    state: OBDeviceState,
+    message: *const std::ffi::c_char,
+    user_data: *mut std::ffi::c_void,
+) {
+    let callback = unsafe { &mut *(user_data as *mut Box<dyn FnMut(OBDeviceState, &str) + Send>) };
+    let message = if message.is_null() {
+        std::borrow::Cow::Borrowed("")
+    } else {
+        unsafe { CStr::from_ptr(message) }.to_string_lossy()
+    };
+    callback(state, &message);
+}
+
+/// Callback that ignores state changes, used to release a previously set callback
+pub(crate) unsafe extern "C" fn state_changed_noop(
+    _state: OBDeviceState,
+    _message: *const std::ffi::c_char,
+    _user_data: *mut std::ffi::c_void,
+) {
+}
+
+/// Data received through `ob_device_get_raw_data`
+struct RawDataBuffer {
+    data: Vec<u8>,
+    state: OBDataTranState,
+}
+
+unsafe extern "C" fn raw_data_trampoline(
+    state: OBDataTranState,
+    chunk: *mut orb::ob_data_chunk,
+    user_data: *mut std::ffi::c_void,
+) {
+    let buffer = unsafe { &mut *(user_data as *mut RawDataBuffer) };
+    buffer.state = state;
+
+    if chunk.is_null() {
+        return;
+    }
+
+    let chunk = unsafe { &*chunk };
+    let (data, size, offset) = ({ chunk.data }, { chunk.size }, { chunk.offset });
+    if data.is_null() || size == 0 {
+        return;
+    }
+
+    let end = offset as usize + size as usize;
+    if buffer.data.len() < end {
+        buffer.data.resize(end, 0);
+    }
+    let bytes = unsafe { std::slice::from_raw_parts(data, size as usize) };
+    buffer.data[offset as usize..end].copy_from_slice(bytes);
+}
+
 /// A class describing device information, representing the name, id, serial number and other basic information of an RGBD camera.
 pub struct OBDeviceInfo {
     inner: *mut orb::ob_device_info,
@@ -68,6 +148,24 @@ impl OBDeviceInfo {
         Ok(unsafe { CStr::from_ptr(ptr) })
     }
 
+    /// Get device IP address (network devices only)
+    pub fn get_ip_address(&self) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(orb::ob_device_info_get_ip_address, self.inner)?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    /// Get device subnet mask (network devices only)
+    pub fn get_subnet_mask(&self) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(orb::ob_device_info_get_subnet_mask, self.inner)?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    /// Get device gateway address (network devices only)
+    pub fn get_gateway(&self) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(orb::ob_device_info_get_gateway, self.inner)?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
     /// Get device minimum supported SDK version
     pub fn get_min_supported_sdk_version(&self) -> Result<&CStr, OBError> {
         let ptr = call_ob_function!(
@@ -112,6 +210,12 @@ impl OBDevice {
         Ok(OBDeviceInfo::new(info))
     }
 
+    /// Get the list of all sensors of the device
+    pub fn get_sensor_list(&self) -> Result<OBSensorList, OBError> {
+        let list = call_ob_function!(orb::ob_device_get_sensor_list, self.inner)?;
+        Ok(OBSensorList::new(list))
+    }
+
     impl_ob_method!(
         /// Check if a device property is supported
         is_property_supported => bool,
@@ -165,6 +269,178 @@ impl OBDevice {
         property_id: OBPropertyID,
     );
 
+    impl_ob_method!(
+        /// Get boolean property range
+        get_bool_property_range => OBBoolPropertyRange,
+        orb::ob_device_get_bool_property_range,
+        property_id: OBPropertyID,
+    );
+
+    impl_ob_method!(
+        /// Get integer property range
+        get_int_property_range => OBIntPropertyRange,
+        orb::ob_device_get_int_property_range,
+        property_id: OBPropertyID,
+    );
+
+    impl_ob_method!(
+        /// Get float property range
+        get_float_property_range => OBFloatPropertyRange,
+        orb::ob_device_get_float_property_range,
+        property_id: OBPropertyID,
+    );
+
+    impl_ob_method!(
+        /// Get the number of properties supported by the device
+        get_supported_property_count => u32,
+        orb::ob_device_get_supported_property_count,
+    );
+
+    impl_ob_method!(
+        /// Get the supported property at the specified index
+        get_supported_property_item => OBPropertyItem,
+        orb::ob_device_get_supported_property_item,
+        index: u32,
+    );
+
+    /// Update the device firmware from an image file, invoking the callback with the progress
+    pub fn update_firmware(
+        &self,
+        path: &CStr,
+        callback: OBFwUpdateCallback,
+        async_: bool,
+        user_data: *mut std::ffi::c_void,
+    ) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_device_update_firmware,
+            self.inner,
+            path.as_ptr(),
+            callback,
+            async_,
+            user_data
+        )
+    }
+
+    /// Update the device firmware from image data, invoking the callback with the progress
+    pub fn update_firmware_from_data(
+        &self,
+        data: &[u8],
+        callback: OBFwUpdateCallback,
+        async_: bool,
+        user_data: *mut std::ffi::c_void,
+    ) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_device_update_firmware_from_data,
+            self.inner,
+            data.as_ptr(),
+            data.len() as u32,
+            callback,
+            async_,
+            user_data
+        )
+    }
+
+    /// Update the optional depth presets of the device, invoking the callback with the progress
+    pub fn update_optional_depth_presets(
+        &self,
+        file_paths: &[[std::ffi::c_char; orb::OB_PATH_MAX as usize]],
+        callback: OBFwUpdateCallback,
+        user_data: *mut std::ffi::c_void,
+    ) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_device_update_optional_depth_presets,
+            self.inner,
+            file_paths.as_ptr(),
+            file_paths.len() as u8,
+            callback,
+            user_data
+        )
+    }
+
+    /// Write customer data to the device flash
+    pub fn write_customer_data(&self, data: &[u8]) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_device_write_customer_data,
+            self.inner,
+            data.as_ptr() as *const std::ffi::c_void,
+            data.len() as u32
+        )
+    }
+
+    /// Read customer data from the device flash into the buffer, returning the size reported by the SDK
+    ///
+    /// The SDK doesn't take the buffer size, so it must hold the largest customer data accepted by the device.
+    pub fn read_customer_data(&self, buffer: &mut [u8]) -> Result<usize, OBError> {
+        let mut data_size = 0u32;
+
+        call_ob_function!(
+            orb::ob_device_read_customer_data,
+            self.inner,
+            buffer.as_mut_ptr() as *mut std::ffi::c_void,
+            &mut data_size
+        )?;
+
+        Ok(data_size as usize)
+    }
+
+    /// Get the raw data of a property, along with the final transfer state
+    pub fn get_raw_data(
+        &self,
+        property_id: OBPropertyID,
+    ) -> Result<(Vec<u8>, OBDataTranState), OBError> {
+        let mut buffer = RawDataBuffer {
+            data: Vec::new(),
+            state: OBDataTranState::StatTransferring,
+        };
+
+        // The SDK invokes the callback synchronously, before returning
+        call_ob_function!(
+            orb::ob_device_get_raw_data,
+            self.inner,
+            property_id,
+            Some(raw_data_trampoline),
+            &mut buffer as *mut RawDataBuffer as *mut std::ffi::c_void
+        )?;
+
+        Ok((buffer.data, buffer.state))
+    }
+
+    /// Set a struct property from its raw bytes
+    pub fn set_raw_struct_property(
+        &self,
+        property_id: OBPropertyID,
+        data: &[u8],
+    ) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_device_set_structured_data,
+            self.inner,
+            property_id,
+            data.as_ptr(),
+            data.len() as u32,
+        )
+    }
+
+    /// Get the raw bytes of a struct property, whose value is at most `size` bytes
+    pub fn get_raw_struct_property(
+        &self,
+        property_id: OBPropertyID,
+        size: usize,
+    ) -> Result<Vec<u8>, OBError> {
+        let mut data = vec![0u8; size];
+        let mut data_size = size as u32;
+
+        call_ob_function!(
+            orb::ob_device_get_structured_data,
+            self.inner,
+            property_id,
+            data.as_mut_ptr(),
+            &mut data_size
+        )?;
+
+        data.truncate(data_size as usize);
+        Ok(data)
+    }
+
     pub fn set_struct_property<T: StructProperty>(&self, value: T::Value) -> Result<(), OBError> {
         let data_size: u32 = size_of::<T::Value>() as u32;
         let value_ptr = (&value as *const T::Value).cast::<u8>() as *mut u8;
@@ -209,6 +485,79 @@ impl OBDevice {
         call_ob_function!(orb::ob_device_load_preset, self.inner, preset_name.as_ptr())
     }
 
+    /// Get the name of the current preset
+    pub fn get_current_preset_name(&self) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(orb::ob_device_get_current_preset_name, self.inner)?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    /// Get the list of available presets
+    pub fn get_available_preset_list(&self) -> Result<OBDevicePresetList, OBError> {
+        let list = call_ob_function!(orb::ob_device_get_available_preset_list, self.inner)?;
+        Ok(OBDevicePresetList::new(list))
+    }
+
+    /// Load a preset from a json file
+    /// The preset is appended to the available preset list, named as the file name.
+    pub fn load_preset_from_json_file(&self, json_file_path: &CStr) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_device_load_preset_from_json_file,
+            self.inner,
+            json_file_path.as_ptr()
+        )
+    }
+
+    /// Load a preset from json data
+    /// The preset is appended to the available preset list with the given name.
+    pub fn load_preset_from_json_data(
+        &self,
+        preset_name: &CStr,
+        data: &[u8],
+    ) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_device_load_preset_from_json_data,
+            self.inner,
+            preset_name.as_ptr(),
+            data.as_ptr(),
+            data.len() as u32
+        )
+    }
+
+    /// Export the current device settings as a preset json file
+    pub fn export_current_settings_as_preset_json_file(
+        &self,
+        json_file_path: &CStr,
+    ) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_device_export_current_settings_as_preset_json_file,
+            self.inner,
+            json_file_path.as_ptr()
+        )
+    }
+
+    /// Export the current device settings as preset json data
+    /// The returned buffer is owned by the SDK and reused on the next call.
+    pub fn export_current_settings_as_preset_json_data(
+        &self,
+        preset_name: &CStr,
+    ) -> Result<&[u8], OBError> {
+        let mut data: *const u8 = std::ptr::null();
+        let mut size: u32 = 0;
+
+        call_ob_function!(
+            orb::ob_device_export_current_settings_as_preset_json_data,
+            self.inner,
+            preset_name.as_ptr(),
+            &mut data,
+            &mut size
+        )?;
+
+        if data.is_null() || size == 0 {
+            return Ok(&[]);
+        }
+        Ok(unsafe { std::slice::from_raw_parts(data, size as usize) })
+    }
+
     impl_ob_method!(
         /// Check if the device supports global timestamp
         is_global_timestamp_supported => bool,
@@ -221,6 +570,153 @@ impl OBDevice {
         orb::ob_device_enable_global_timestamp,
         enabled: bool,
     );
+
+    impl_ob_method!(
+        /// Get the supported multi device sync mode bitmap of the device
+        get_supported_multi_device_sync_mode_bitmap => u16,
+        orb::ob_device_get_supported_multi_device_sync_mode_bitmap,
+    );
+
+    /// Set the multi device sync configuration of the device
+    pub fn set_multi_device_sync_config(
+        &self,
+        config: &OBMultiDeviceSyncConfig,
+    ) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_device_set_multi_device_sync_config,
+            self.inner,
+            config as *const OBMultiDeviceSyncConfig
+        )
+    }
+
+    impl_ob_method!(
+        /// Get the multi device sync configuration of the device
+        get_multi_device_sync_config => OBMultiDeviceSyncConfig,
+        orb::ob_device_get_multi_device_sync_config,
+    );
+
+    impl_ob_method!(
+        /// Send the capture command to the device (software triggering mode)
+        trigger_capture => (),
+        orb::ob_device_trigger_capture,
+    );
+
+    impl_ob_method!(
+        /// Reboot the device
+        reboot => (),
+        orb::ob_device_reboot,
+    );
+
+    impl_ob_method!(
+        /// Enable or disable the device heartbeat
+        enable_heartbeat => (),
+        orb::ob_device_enable_heartbeat,
+        enable: bool,
+    );
+
+    impl_ob_method!(
+        /// Get the current device state
+        get_device_state => OBDeviceState,
+        orb::ob_device_get_device_state,
+    );
+
+    /// Set the callback invoked when the device state changes, replacing the previous one
+    pub fn set_state_changed_callback(
+        &self,
+        callback: OBDeviceStateCallback,
+        user_data: *mut std::ffi::c_void,
+    ) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_device_set_state_changed_callback,
+            self.inner,
+            callback,
+            user_data
+        )
+    }
+
+    /// Get the list of depth work modes supported by the device
+    pub fn get_depth_work_mode_list(&self) -> Result<OBDepthWorkModeList, OBError> {
+        let list = call_ob_function!(orb::ob_device_get_depth_work_mode_list, self.inner)?;
+        Ok(OBDepthWorkModeList::new(list))
+    }
+
+    impl_ob_method!(
+        /// Get the current depth work mode
+        get_current_depth_work_mode => OBDepthWorkMode,
+        orb::ob_device_get_current_depth_work_mode,
+    );
+
+    /// Switch the depth work mode
+    pub fn switch_depth_work_mode(
+        &self,
+        work_mode: &OBDepthWorkMode,
+    ) -> Result<OBErrorCode, OBError> {
+        call_ob_function!(
+            orb::ob_device_switch_depth_work_mode,
+            self.inner,
+            work_mode as *const OBDepthWorkMode
+        )
+    }
+
+    /// Switch the depth work mode by name
+    pub fn switch_depth_work_mode_by_name(&self, mode_name: &CStr) -> Result<OBErrorCode, OBError> {
+        call_ob_function!(
+            orb::ob_device_switch_depth_work_mode_by_name,
+            self.inner,
+            mode_name.as_ptr()
+        )
+    }
+}
+
+/// List of device presets
+pub struct OBDevicePresetList {
+    inner: *mut orb::ob_device_preset_list,
+}
+
+drop_ob_object!(OBDevicePresetList, ob_delete_preset_list);
+
+impl OBDevicePresetList {
+    pub(crate) fn new(inner: *mut orb::ob_device_preset_list) -> Self {
+        OBDevicePresetList { inner }
+    }
+
+    impl_ob_method!(
+        /// Get the number of presets in the list
+        get_count => u32,
+        orb::ob_device_preset_list_get_count,
+    );
+
+    /// Get the name of the preset at the specified index
+    pub fn get_name(&self, index: u32) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(orb::ob_device_preset_list_get_name, self.inner, index)?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+}
+
+/// List of depth work modes
+pub struct OBDepthWorkModeList {
+    inner: *mut orb::ob_depth_work_mode_list,
+}
+
+drop_ob_object!(OBDepthWorkModeList, ob_delete_depth_work_mode_list);
+
+impl OBDepthWorkModeList {
+    pub(crate) fn new(inner: *mut orb::ob_depth_work_mode_list) -> Self {
+        OBDepthWorkModeList { inner }
+    }
+
+    impl_ob_method!(
+        /// Get the number of depth work modes in the list
+        get_count => u32,
+        orb::ob_depth_work_mode_list_get_count,
+    );
+
+    impl_ob_method!(
+        /// Get the depth work mode at the specified index
+        get_item => OBDepthWorkMode,
+        orb::ob_depth_work_mode_list_get_item,
+        index: u32,
+    );
 }
 
 /// List of devices
@@ -246,4 +742,138 @@ impl OBDeviceList {
         let device = call_ob_function!(orb::ob_device_list_get_device, self.inner, index)?;
         Ok(OBDevice::new(device))
     }
+
+    /// Get the device object with the specified serial number
+    pub fn get_device_by_serial_number(&self, serial_number: &CStr) -> Result<OBDevice, OBError> {
+        let device = call_ob_function!(
+            orb::ob_device_list_get_device_by_serial_number,
+            self.inner,
+            serial_number.as_ptr()
+        )?;
+        Ok(OBDevice::new(device))
+    }
+
+    /// Get the device object with the specified UID
+    pub fn get_device_by_uid(&self, uid: &CStr) -> Result<OBDevice, OBError> {
+        let device = call_ob_function!(
+            orb::ob_device_list_get_device_by_uid,
+            self.inner,
+            uid.as_ptr()
+        )?;
+        Ok(OBDevice::new(device))
+    }
+
+    /// Get the name of the device at the specified index
+    pub fn get_device_name(&self, index: u32) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(orb::ob_device_list_get_device_name, self.inner, index)?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    impl_ob_method!(
+        /// Get the PID of the device at the specified index
+        get_device_pid => i32,
+        orb::ob_device_list_get_device_pid,
+        index: u32,
+    );
+
+    impl_ob_method!(
+        /// Get the VID of the device at the specified index
+        get_device_vid => i32,
+        orb::ob_device_list_get_device_vid,
+        index: u32,
+    );
+
+    /// Get the UID of the device at the specified index
+    pub fn get_device_uid(&self, index: u32) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(orb::ob_device_list_get_device_uid, self.inner, index)?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    /// Get the serial number of the device at the specified index
+    pub fn get_device_serial_number(&self, index: u32) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(
+            orb::ob_device_list_get_device_serial_number,
+            self.inner,
+            index
+        )?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    /// Get the connection type of the device at the specified index
+    pub fn get_device_connection_type(&self, index: u32) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(
+            orb::ob_device_list_get_device_connection_type,
+            self.inner,
+            index
+        )?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    /// Get the IP address of the device at the specified index (network devices only)
+    pub fn get_device_ip_address(&self, index: u32) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(orb::ob_device_list_get_device_ip_address, self.inner, index)?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    /// Get the subnet mask of the device at the specified index (network devices only)
+    pub fn get_device_subnet_mask(&self, index: u32) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(
+            orb::ob_device_list_get_device_subnet_mask,
+            self.inner,
+            index
+        )?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    /// Get the gateway of the device at the specified index (network devices only)
+    pub fn get_device_gateway(&self, index: u32) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(orb::ob_device_list_get_device_gateway, self.inner, index)?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    /// Get the MAC address of the host network interface connected to the device at the specified index
+    pub fn get_device_local_mac(&self, index: u32) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(orb::ob_device_list_get_device_local_mac, self.inner, index)?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    /// Get the IP address of the host network interface connected to the device at the specified index
+    pub fn get_device_local_ip(&self, index: u32) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(orb::ob_device_list_get_device_local_ip, self.inner, index)?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    impl_ob_method!(
+        /// Get the subnet length of the host network interface connected to the device at the specified index
+        get_device_local_subnet_length => u8,
+        orb::ob_device_list_get_device_local_subnet_length,
+        index: u32,
+    );
+
+    /// Get the gateway of the host network interface connected to the device at the specified index
+    pub fn get_device_local_gateway(&self, index: u32) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(
+            orb::ob_device_list_get_device_local_gateway,
+            self.inner,
+            index
+        )?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    /// Get the name of the host network interface connected to the device at the specified index
+    pub fn get_device_local_net_if_name(&self, index: u32) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(
+            orb::ob_device_list_get_device_local_net_if_name,
+            self.inner,
+            index
+        )?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    impl_ob_method!(
+        /// Get the IP configuration source of the device at the specified index
+        get_device_ip_source_type => OBIpSourceType,
+        orb::ob_device_list_get_device_ip_source_type,
+        index: u32,
+    );
 }
@@ -1,8 +1,10 @@
 //! Filter related operations
 use std::ffi::CStr;
+use std::sync::atomic::AtomicBool;
 
 use super::frame::OBFrame;
 use super::orb::OBFilterConfigValueType;
+use super::sensor::OBFrameCallback;
 use super::stream::OBStreamProfile;
 use super::{OBError, call_ob_function, drop_ob_object, impl_ob_method, orb};
 
@@ -86,30 +88,120 @@ impl OBFilterConfigSchemaList {
     }
 }
 
+/// List of filters
+pub struct OBFilterList {
+    inner: *mut orb::ob_filter_list,
+}
+
+drop_ob_object!(OBFilterList, ob_delete_filter_list);
+
+impl OBFilterList {
+    pub(crate) fn new(inner: *mut orb::ob_filter_list) -> Self {
+        OBFilterList { inner }
+    }
+
+    impl_ob_method!(
+        /// Get the number of filters in the list
+        get_count => u32,
+        orb::ob_filter_list_get_count,
+    );
+
+    /// Get the filter at the specified index
+    pub fn get_filter(&self, index: u32) -> Result<OBFilter, OBError> {
+        let filter = call_ob_function!(orb::ob_filter_list_get_filter, self.inner, index)?;
+        Ok(OBFilter::from_raw(filter))
+    }
+}
+
+/// Callback that releases the processed frames, used to release a previously set callback
+pub(crate) unsafe extern "C" fn frame_release_trampoline(
+    frame: *mut orb::ob_frame,
+    _user_data: *mut std::ffi::c_void,
+) {
+    drop(OBFrame::new(frame));
+}
+
+/// Get the vendor specific code of a filter by name
+///
+/// The code is copied right away, as the SDK doesn't document the lifetime of the returned string.
+pub fn get_vendor_specific_code(name: &CStr) -> Result<String, OBError> {
+    let ptr = call_ob_function!(orb::ob_filter_get_vendor_specific_code, name.as_ptr())?;
+    if ptr.is_null() {
+        return Ok(String::new());
+    }
+
+    Ok(unsafe { CStr::from_ptr(ptr) }
+        .to_string_lossy()
+        .into_owned())
+}
+
 /// Generic Filter Class
 pub struct OBFilter {
     inner: *mut orb::ob_filter,
+    /// The SDK keeps a single callback per filter
+    pub(crate) callback_registered: AtomicBool,
 }
 
 drop_ob_object!(OBFilter, ob_delete_filter);
 
 impl OBFilter {
+    fn from_raw(inner: *mut orb::ob_filter) -> Self {
+        OBFilter {
+            inner,
+            callback_registered: AtomicBool::new(false),
+        }
+    }
+
     /// Create a filter object by name
     pub fn new(name: &CStr) -> Result<Option<Self>, OBError> {
         let filter = call_ob_function!(orb::ob_create_filter, name.as_ptr())?;
         Ok(if filter.is_null() {
             None
         } else {
-            Some(OBFilter { inner: filter })
+            Some(OBFilter::from_raw(filter))
         })
     }
 
+    /// Create a private filter object by name, activated with the vendor provided key
+    pub fn new_private(name: &CStr, activation_key: &CStr) -> Result<Option<Self>, OBError> {
+        let filter = call_ob_function!(
+            orb::ob_create_private_filter,
+            name.as_ptr(),
+            activation_key.as_ptr()
+        )?;
+        Ok(if filter.is_null() {
+            None
+        } else {
+            Some(OBFilter::from_raw(filter))
+        })
+    }
+
+    /// Get the name of the filter
+    pub fn get_name(&self) -> Result<&CStr, OBError> {
+        let ptr = call_ob_function!(orb::ob_filter_get_name, self.inner)?;
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
     /// Process the input frame and return the processed frame
     pub fn process(&self, input_frame: &OBFrame) -> Result<OBFrame, OBError> {
         let frame = call_ob_function!(orb::ob_filter_process, self.inner, input_frame.inner())?;
         Ok(OBFrame::new(frame))
     }
 
+    /// Set the callback invoked with each frame processed asynchronously, replacing the previous one
+    pub fn set_callback(
+        &self,
+        callback: OBFrameCallback,
+        user_data: *mut std::ffi::c_void,
+    ) -> Result<(), OBError> {
+        call_ob_function!(orb::ob_filter_set_callback, self.inner, callback, user_data)
+    }
+
+    /// Push a frame to be processed asynchronously, the result is delivered to the callback
+    pub fn push_frame(&self, frame: &OBFrame) -> Result<(), OBError> {
+        call_ob_function!(orb::ob_filter_push_frame, self.inner, frame.inner())
+    }
+
     /// Get the filter config schema list of the filter
     pub fn get_config_schema_list(&self) -> Result<OBFilterConfigSchemaList, OBError> {
         let list = call_ob_function!(orb::ob_filter_get_config_schema_list, self.inner)?;
@@ -132,6 +224,25 @@ impl OBFilter {
         )
     }
 
+    impl_ob_method!(
+        /// Reset the filter, clearing its internal state and cached frames
+        reset => (),
+        orb::ob_filter_reset,
+    );
+
+    impl_ob_method!(
+        /// Enable or disable the filter
+        enable => (),
+        orb::ob_filter_enable,
+        enable: bool,
+    );
+
+    impl_ob_method!(
+        /// Check if the filter is enabled
+        is_enabled => bool,
+        orb::ob_filter_is_enabled,
+    );
+
     /// Set the stream profile to which the filter will align to.
     /// Only valid for Align filter
     pub fn set_align_to(&self, stream_profile: &OBStreamProfile) -> Result<(), OBError> {
@@ -1,5 +1,5 @@
 //! Frame and FrameSet related operations
-use super::orb::OBFormat;
+use super::orb::{OBAccelValue, OBFormat, OBFrameMetadataType, OBFrameType, OBGyroValue};
 use super::{OBError, call_ob_function, drop_ob_object, impl_ob_method, orb};
 
 /// A container of one or multiple frames
@@ -18,6 +18,12 @@ impl OBFrame {
         self.inner
     }
 
+    impl_ob_method!(
+        /// Get the type of the frame
+        get_type => OBFrameType,
+        orb::ob_frame_get_type,
+    );
+
     /// Get the frame timestamp (also known as device timestamp, hardware timestamp) of the frame in microseconds.
     /// The hardware timestamp is the time point when the frame was captured by the device (Typically in the mid-exposure, unless otherwise stated), on device clock domain.
     pub fn get_timestamp_us(&self) -> Result<u64, OBError> {
@@ -61,11 +67,41 @@ impl OBFrame {
         Ok(unsafe { std::slice::from_raw_parts(data as *const u8, size) })
     }
 
+    /// Get the raw metadata buffer of a frame
+    pub fn get_metadata(&self) -> Result<&[u8], OBError> {
+        let size = call_ob_function!(orb::ob_frame_get_metadata_size, self.inner)? as usize;
+        let data = call_ob_function!(orb::ob_frame_get_metadata, self.inner)?;
+        if data.is_null() || size == 0 {
+            return Ok(&[]);
+        }
+        Ok(unsafe { std::slice::from_raw_parts(data as *const u8, size) })
+    }
+
+    impl_ob_method!(
+        /// Check if the frame contains the specified metadata
+        has_metadata => bool,
+        orb::ob_frame_has_metadata,
+        metadata_type: OBFrameMetadataType,
+    );
+
+    impl_ob_method!(
+        /// Get the value of the specified metadata
+        get_metadata_value => i64,
+        orb::ob_frame_get_metadata_value,
+        metadata_type: OBFrameMetadataType,
+    );
+
     /// Get the format of the frame
     pub fn get_format(&self) -> Result<OBFormat, OBError> {
         Ok(call_ob_function!(orb::ob_frame_get_format, self.inner)?)
     }
 
+    /// Push a frame to the frameset, replacing the frame of the same type.
+    /// Only valid for frameset frames.
+    pub fn push_frame(&self, frame: &OBFrame) -> Result<(), OBError> {
+        call_ob_function!(orb::ob_frameset_push_frame, self.inner, frame.inner)
+    }
+
     /// Get the depth frame from the frameset.
     /// Only valid for frameset frames.
     pub fn get_depth_frame(&self) -> Result<Option<OBFrame>, OBError> {
@@ -88,6 +124,17 @@ impl OBFrame {
         })
     }
 
+    /// Get the infrared frame from the frameset.
+    /// Only valid for frameset frames.
+    pub fn get_ir_frame(&self) -> Result<Option<OBFrame>, OBError> {
+        let frame = call_ob_function!(orb::ob_frameset_get_ir_frame, self.inner)?;
+        Ok(if frame.is_null() {
+            None
+        } else {
+            Some(OBFrame::new(frame))
+        })
+    }
+
     /// Get the point cloud frame from the frameset.
     /// Only valid for frameset frames.
     pub fn get_points_frame(&self) -> Result<Option<OBFrame>, OBError> {
@@ -99,6 +146,17 @@ impl OBFrame {
         })
     }
 
+    /// Get a frame of a specific type from the frameset.
+    /// Only valid for frameset frames.
+    pub fn get_frame(&self, frame_type: OBFrameType) -> Result<Option<OBFrame>, OBError> {
+        let frame = call_ob_function!(orb::ob_frameset_get_frame, self.inner, frame_type)?;
+        Ok(if frame.is_null() {
+            None
+        } else {
+            Some(OBFrame::new(frame))
+        })
+    }
+
     impl_ob_method!(
         /// Get the width of the video frame.
         /// Only valid for video frames.
@@ -140,4 +198,32 @@ impl OBFrame {
         get_depth_scale => f32,
         orb::ob_depth_frame_get_value_scale,
     );
+
+    impl_ob_method!(
+        /// Get the accelerometer frame data.
+        /// Only valid for accelerometer frames.
+        get_accel_value => OBAccelValue,
+        orb::ob_accel_frame_get_value,
+    );
+
+    impl_ob_method!(
+        /// Get the temperature when acquiring the accelerometer frame.
+        /// Only valid for accelerometer frames.
+        get_accel_temperature => f32,
+        orb::ob_accel_frame_get_temperature,
+    );
+
+    impl_ob_method!(
+        /// Get the gyroscope frame data.
+        /// Only valid for gyroscope frames.
+        get_gyro_value => OBGyroValue,
+        orb::ob_gyro_frame_get_value,
+    );
+
+    impl_ob_method!(
+        /// Get the temperature when acquiring the gyroscope frame.
+        /// Only valid for gyroscope frames.
+        get_gyro_temperature => f32,
+        orb::ob_gyro_frame_get_temperature,
+    );
 }
@@ -1,6 +1,7 @@
 //! Pipeline configuration and management
 use crate::sys::orb::{
-    OBAlignMode, OBCalibrationParam, OBCameraParam, OBFrameAggregateOutputMode, OBSensorType,
+    OBAccelFullScaleRange, OBAccelSampleRate, OBAlignMode, OBCalibrationParam, OBCameraParam,
+    OBFrameAggregateOutputMode, OBGyroFullScaleRange, OBGyroSampleRate, OBSensorType,
 };
 
 use super::device::OBDevice;
@@ -51,6 +52,22 @@ impl OBConfig {
         orb::ob_config_set_frame_aggregate_output_mode,
         mode: OBFrameAggregateOutputMode,
     );
+
+    impl_ob_method!(
+        /// Enable the accelerometer stream with the specified parameters
+        enable_accel_stream => (),
+        orb::ob_config_enable_accel_stream,
+        full_scale_range: OBAccelFullScaleRange,
+        sample_rate: OBAccelSampleRate,
+    );
+
+    impl_ob_method!(
+        /// Enable the gyroscope stream with the specified parameters
+        enable_gyro_stream => (),
+        orb::ob_config_enable_gyro_stream,
+        full_scale_range: OBGyroFullScaleRange,
+        sample_rate: OBGyroSampleRate,
+    );
 }
 
 /// Camera pipeline class
@@ -2,7 +2,15 @@
 #![allow(missing_docs)]
 #![allow(unused)]
 
-use crate::{device::Device, error::OrbbecError};
+use crate::{
+    PermissionType, PropertyId, PropertyType,
+    device::Device,
+    error::{OrbbecError, OrbbecErrorData},
+    sys::orb::{
+        OBBoolPropertyRange, OBDataTranState, OBFloatPropertyRange, OBIntPropertyRange,
+        OBPropertyItem,
+    },
+};
 
 /// All device properties must implement this trait
 pub trait Property {
@@ -21,6 +29,256 @@ pub trait GetProperty: Property {
 
 pub trait StructProperty: Property {}
 
+/// All device properties read as variable-length raw data must implement this trait
+pub trait RawDataProperty: Property {}
+
+/// All device properties with a value range must implement this trait
+pub trait RangedProperty: Property<Value: RangeValue> {
+    fn range_from(device: &Device) -> Result<PropertyRange<Self::Value>, OrbbecError>;
+}
+
+/// Range of values accepted by a device property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PropertyRange<T> {
+    /// Minimum value
+    pub min: T,
+    /// Maximum value
+    pub max: T,
+    /// Step between two valid values
+    pub step: T,
+    /// Default value
+    pub default: T,
+    /// Current value
+    pub current: T,
+}
+
+impl<T: RangeValue> PropertyRange<T> {
+    /// Check if a value is accepted by the range
+    ///
+    /// Integer values must also be a multiple of `step` away from `min`.
+    pub fn contains(&self, value: T) -> bool {
+        value.is_in(self)
+    }
+}
+
+/// Values that can be checked against a [`PropertyRange`]
+pub trait RangeValue: Copy + PartialOrd + std::fmt::Debug {
+    fn is_in(self, range: &PropertyRange<Self>) -> bool {
+        self >= range.min && self <= range.max
+    }
+}
+
+impl RangeValue for bool {}
+
+impl RangeValue for f32 {}
+
+impl RangeValue for i32 {
+    fn is_in(self, range: &PropertyRange<Self>) -> bool {
+        if self < range.min || self > range.max {
+            return false;
+        }
+
+        range.step <= 0 || (self as i64 - range.min as i64) % range.step as i64 == 0
+    }
+}
+
+impl From<OBBoolPropertyRange> for PropertyRange<bool> {
+    fn from(ob: OBBoolPropertyRange) -> Self {
+        PropertyRange {
+            min: ob.min,
+            max: ob.max,
+            step: ob.step,
+            default: ob.def,
+            current: ob.cur,
+        }
+    }
+}
+
+impl From<OBIntPropertyRange> for PropertyRange<i32> {
+    fn from(ob: OBIntPropertyRange) -> Self {
+        PropertyRange {
+            min: { ob.min },
+            max: { ob.max },
+            step: { ob.step },
+            default: { ob.def },
+            current: { ob.cur },
+        }
+    }
+}
+
+impl From<OBFloatPropertyRange> for PropertyRange<f32> {
+    fn from(ob: OBFloatPropertyRange) -> Self {
+        PropertyRange {
+            min: { ob.min },
+            max: { ob.max },
+            step: { ob.step },
+            default: { ob.def },
+            current: { ob.cur },
+        }
+    }
+}
+
+/// Description of a property supported by a device
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyItem {
+    /// Property ID
+    pub id: PropertyId,
+    /// Property name, as reported by the SDK
+    pub name: String,
+    /// Property value type
+    pub property_type: PropertyType,
+    /// Property read and write permission
+    pub permission: PermissionType,
+}
+
+impl PropertyItem {
+    /// Check if the item describes the typed property `P`
+    ///
+    /// ### Type Parameters
+    /// * `P` - The struct implementing [`Property`] to compare with (e.g., `DepthAlignHardware`).
+    pub fn is<P: Property>(&self) -> bool {
+        self.id == P::ID
+    }
+
+    /// Check if the property can be read
+    pub fn is_readable(&self) -> bool {
+        matches!(
+            self.permission,
+            PermissionType::Read | PermissionType::ReadWrite | PermissionType::Any
+        )
+    }
+
+    /// Check if the property can be written
+    pub fn is_writable(&self) -> bool {
+        matches!(
+            self.permission,
+            PermissionType::Write | PermissionType::ReadWrite | PermissionType::Any
+        )
+    }
+}
+
+impl From<OBPropertyItem> for PropertyItem {
+    fn from(ob: OBPropertyItem) -> Self {
+        let name = if ob.name.is_null() {
+            String::new()
+        } else {
+            unsafe { std::ffi::CStr::from_ptr(ob.name) }
+                .to_string_lossy()
+                .into_owned()
+        };
+
+        PropertyItem {
+            id: ob.id,
+            name,
+            property_type: ob.type_,
+            permission: ob.permission,
+        }
+    }
+}
+
+/// A dynamically typed property value, for properties selected at runtime
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PropertyValue {
+    /// Boolean property value
+    Bool(bool),
+    /// Integer property value
+    Int(i32),
+    /// Float property value
+    Float(f32),
+}
+
+impl PropertyValue {
+    /// Get the property type matching the value
+    pub fn property_type(&self) -> PropertyType {
+        match self {
+            PropertyValue::Bool(_) => PropertyType::BoolProperty,
+            PropertyValue::Int(_) => PropertyType::IntProperty,
+            PropertyValue::Float(_) => PropertyType::FloatProperty,
+        }
+    }
+}
+
+impl From<bool> for PropertyValue {
+    fn from(value: bool) -> Self {
+        PropertyValue::Bool(value)
+    }
+}
+
+impl From<i32> for PropertyValue {
+    fn from(value: i32) -> Self {
+        PropertyValue::Int(value)
+    }
+}
+
+impl From<f32> for PropertyValue {
+    fn from(value: f32) -> Self {
+        PropertyValue::Float(value)
+    }
+}
+
+/// Find a bool, int or float property by name
+///
+/// Names are the same as the typed property structs (e.g. `"ColorExposure"`).
+/// ### Arguments
+/// * `name` - The name of the property
+pub fn find_property(name: &str) -> Option<(PropertyId, PropertyType)> {
+    property_id_types::PROPERTY_TABLE
+        .binary_search_by(|(entry, _, _)| (*entry).cmp(name))
+        .ok()
+        .map(|i| {
+            let (_, id, property_type) = property_id_types::PROPERTY_TABLE[i];
+            (id, property_type)
+        })
+}
+
+/// Find the name and value type of a bool, int or float property from its ID
+pub(crate) fn scalar_property_name(id: PropertyId) -> Option<(&'static str, PropertyType)> {
+    property_id_types::PROPERTY_TABLE
+        .iter()
+        .find(|(_, entry, _)| *entry == id)
+        .map(|(name, _, property_type)| (*name, *property_type))
+}
+
+/// Find the ID and value size of a struct property with a known value type by name
+pub(crate) fn find_struct_property(name: &str) -> Option<(PropertyId, usize)> {
+    property_id_types::STRUCT_PROPERTY_TABLE
+        .binary_search_by(|(entry, _, _)| (*entry).cmp(name))
+        .ok()
+        .map(|i| {
+            let (_, id, size) = property_id_types::STRUCT_PROPERTY_TABLE[i];
+            (id, size)
+        })
+}
+
+/// Find the name and value size of a struct property with a known value type from its ID
+pub(crate) fn struct_property_name(id: PropertyId) -> Option<(&'static str, usize)> {
+    property_id_types::STRUCT_PROPERTY_TABLE
+        .iter()
+        .find(|(_, entry, _)| *entry == id)
+        .map(|(name, _, size)| (*name, *size))
+}
+
+/// Read the raw data of a property, failing if the transfer did not complete
+pub(crate) fn get_raw_data(device: &Device, id: PropertyId) -> Result<Vec<u8>, OrbbecError> {
+    let (data, state) = device.inner.get_raw_data(id).map_err(OrbbecError::from)?;
+
+    match state {
+        OBDataTranState::StatDone | OBDataTranState::StatVerifyDone => Ok(data),
+        OBDataTranState::ErrUnsupported => {
+            Err(OrbbecError::UnsupportedOperation(raw_data_error(id, state)))
+        }
+        _ => Err(OrbbecError::Unknown(raw_data_error(id, state))),
+    }
+}
+
+fn raw_data_error(id: PropertyId, state: OBDataTranState) -> OrbbecErrorData {
+    OrbbecErrorData {
+        message: format!("Failed to read raw data: {state:?}"),
+        function: "ob_device_get_raw_data".to_string(),
+        args: format!("{id:?}"),
+    }
+}
+
 macro_rules! define_property_base {
     ($name:ident, $value:ty, $doc:literal) => {
         #[doc = $doc]
@@ -61,6 +319,21 @@ macro_rules! define_bool_property {
                     .map_err($crate::error::OrbbecError::from)
             }
         }
+
+        impl $crate::sys::prop::RangedProperty for $name {
+            fn range_from(
+                device: &$crate::device::Device,
+            ) -> Result<
+                $crate::sys::prop::PropertyRange<<$name as $crate::sys::prop::Property>::Value>,
+                $crate::error::OrbbecError,
+            > {
+                device
+                    .inner
+                    .get_bool_property_range(<$name as $crate::sys::prop::Property>::ID)
+                    .map($crate::sys::prop::PropertyRange::from)
+                    .map_err($crate::error::OrbbecError::from)
+            }
+        }
     };
 }
 
@@ -91,6 +364,21 @@ macro_rules! define_int_property {
                     .map_err($crate::error::OrbbecError::from)
             }
         }
+
+        impl $crate::sys::prop::RangedProperty for $name {
+            fn range_from(
+                device: &$crate::device::Device,
+            ) -> Result<
+                $crate::sys::prop::PropertyRange<<$name as $crate::sys::prop::Property>::Value>,
+                $crate::error::OrbbecError,
+            > {
+                device
+                    .inner
+                    .get_int_property_range(<$name as $crate::sys::prop::Property>::ID)
+                    .map($crate::sys::prop::PropertyRange::from)
+                    .map_err($crate::error::OrbbecError::from)
+            }
+        }
     };
 }
 
@@ -121,6 +409,21 @@ macro_rules! define_float_property {
                     .map_err($crate::error::OrbbecError::from)
             }
         }
+
+        impl $crate::sys::prop::RangedProperty for $name {
+            fn range_from(
+                device: &$crate::device::Device,
+            ) -> Result<
+                $crate::sys::prop::PropertyRange<<$name as $crate::sys::prop::Property>::Value>,
+                $crate::error::OrbbecError,
+            > {
+                device
+                    .inner
+                    .get_float_property_range(<$name as $crate::sys::prop::Property>::ID)
+                    .map($crate::sys::prop::PropertyRange::from)
+                    .map_err($crate::error::OrbbecError::from)
+            }
+        }
     };
 }
 
@@ -156,8 +459,115 @@ macro_rules! define_struct_property {
     };
 }
 
+/// Decode the little-endian elements of a raw data property, ignoring trailing bytes
+pub(crate) fn decode_raw_data<T, const N: usize>(
+    data: &[u8],
+    from_le_bytes: fn([u8; N]) -> T,
+) -> Vec<T> {
+    // Unwrap is safe because chunks_exact only yields slices of N bytes
+    data.chunks_exact(N)
+        .map(|bytes| from_le_bytes(bytes.try_into().unwrap()))
+        .collect()
+}
+
+macro_rules! define_raw_data_property {
+    ($name:ident, $element:ty, $doc:literal) => {
+        define_property_base!($name, Vec<$element>, $doc);
+
+        impl $crate::sys::prop::RawDataProperty for $name {}
+
+        impl $crate::sys::prop::GetProperty for $name {
+            fn get_from(
+                device: &$crate::device::Device,
+            ) -> Result<<$name as $crate::sys::prop::Property>::Value, $crate::error::OrbbecError>
+            {
+                let data = $crate::sys::prop::get_raw_data(
+                    device,
+                    <$name as $crate::sys::prop::Property>::ID,
+                )?;
+
+                Ok($crate::sys::prop::decode_raw_data(
+                    &data,
+                    <$element>::from_le_bytes,
+                ))
+            }
+        }
+    };
+}
+
 mod property_id_types;
 mod structs;
 
 pub use property_id_types::*;
 pub use structs::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_property_table_sorted() {
+        let table = property_id_types::PROPERTY_TABLE;
+        assert!(table.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_struct_property_table_sorted() {
+        let table = property_id_types::STRUCT_PROPERTY_TABLE;
+        assert!(table.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_find_property() {
+        assert_eq!(
+            find_property("ColorExposure"),
+            Some((PropertyId::ColorExposure, PropertyType::IntProperty))
+        );
+        assert_eq!(
+            find_property("Watchdog"),
+            Some((PropertyId::Watchdog, PropertyType::BoolProperty))
+        );
+        assert_eq!(find_property("colorexposure"), None);
+        assert_eq!(find_property("ColorAeRoi"), None);
+    }
+
+    #[test]
+    fn test_int_range_contains() {
+        let range = PropertyRange {
+            min: 1,
+            max: 31,
+            step: 2,
+            default: 1,
+            current: 5,
+        };
+
+        assert!(range.contains(1));
+        assert!(range.contains(31));
+        assert!(range.contains(7));
+        assert!(!range.contains(8));
+        assert!(!range.contains(0));
+        assert!(!range.contains(33));
+    }
+
+    #[test]
+    fn test_float_range_contains() {
+        let range = PropertyRange {
+            min: 0.5,
+            max: 2.0,
+            step: 0.1,
+            default: 1.0,
+            current: 1.0,
+        };
+
+        assert!(range.contains(0.5));
+        assert!(range.contains(1.33));
+        assert!(!range.contains(2.01));
+    }
+
+    #[test]
+    fn test_decode_raw_data() {
+        let data = [0x01, 0x00, 0x34, 0x12, 0xff];
+        assert_eq!(decode_raw_data(&data, u16::from_le_bytes), [1, 0x1234]);
+        assert!(decode_raw_data(&[], u16::from_le_bytes).is_empty());
+    }
+}
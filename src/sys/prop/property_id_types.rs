@@ -1,14 +1,18 @@
 use super::structs::OBDeviceTime;
 use crate::sys::orb::{
     OBBaselineCalibrationParam, OBDeviceSerialNumber, OBDeviceTemperature, OBDispOffsetConfig,
-    OBMultiDeviceSyncConfig, OBPresetResolutionConfig, OBRegionOfInterest,
+    OBHdrConfig, OBMultiDeviceSyncConfig, OBNetIpConfig, OBNetIpConfigV2, OBPresetResolutionConfig,
+    OBPropertyID, OBPropertyType, OBRegionOfInterest, OBTofExposureThresholdControl,
 };
 define_bool_property!(
     AntiCollusionActivationStatus,
     "@brief Anti_collusion activation status"
 );
-///This property is a struct, but we don't have a struct value type
-struct AsicSerialNumber;
+define_struct_property!(
+    AsicSerialNumber,
+    OBDeviceSerialNumber,
+    "@brief ASIC serial number"
+);
 define_bool_property!(
     AutoCaptureEnable,
     "@brief soft trigger auto capture enable, use in OB_MULTI_DEVICE_SYNC_MODE_SOFTWARE_TRIGGERING mode"
@@ -167,8 +171,11 @@ define_int_property!(
     DepthGain,
     "@brief Depth camera gain adjustment (infrared cameras will be set synchronously under some models of devices)"
 );
-///This property is a struct, but we don't have a struct value type
-struct DepthHdrConfig;
+define_struct_property!(
+    DepthHdrConfig,
+    OBHdrConfig,
+    "@brief Using to configure the depth sensor's HDR mode\n @brief The Value type is @ref OBHdrConfig\n\n @attention After enable HDR mode, the depth sensor auto exposure will be disabled."
+);
 define_bool_property!(DepthHolefilter, "@brief Depth Holefilter");
 define_int_property!(
     DepthIndustryMode,
@@ -189,8 +196,11 @@ define_int_property!(
     DepthPrecisionLevel,
     "@brief the depth precision level, which may change the depth frame data unit, needs to be confirmed through the ValueScale interface of\n DepthFrame"
 );
-///This property is a struct, but we don't have a struct value type
-struct DepthPrecisionSupportList;
+define_raw_data_property!(
+    DepthPrecisionSupportList,
+    u16,
+    "@brief A list of depth accuracy levels, returning an array of uin16_t, corresponding to the enumeration"
+);
 define_bool_property!(
     DepthRmFilter,
     "@brief Depth effect dedistortion, true: on, false: off. mutually exclusive with D2C function, RM_Filter disable When hardware or software D2C is enabled."
@@ -227,10 +237,16 @@ define_bool_property!(
     DeviceInRecoveryMode,
     "@brief Query whether the current device is running in recovery mode (read-only)"
 );
-///This property is a struct, but we don't have a struct value type
-struct DeviceIpAddrConfig;
-///This property is a struct, but we don't have a struct value type
-struct DeviceIpAddrConfigV2;
+define_struct_property!(
+    DeviceIpAddrConfig,
+    OBNetIpConfig,
+    "@brief Device IP address configuration\n @see OBNetIpConfig"
+);
+define_struct_property!(
+    DeviceIpAddrConfigV2,
+    OBNetIpConfigV2,
+    "@brief Device IP address configuration v2\n @see OBNetIpConfigV2"
+);
 define_int_property!(
     DeviceIpMode,
     "@brief Device IP mode\n @param value\n   - 0: AMR Sensor Mode.\n        Typically configured for ehternet interface sensors for AMRs.\n        When DHCP is enabled and the device fails to obtain a valid IP address, it falls back to Persistent IP.\n        If neither of Persistent IP and DHCP is specified, Persistent IP is enabled by default.\n\n   - 1: Industrial Sensor Mode.\n        Typically configured for ehternet interface sensors for industrial applications.\n        When DHCP is enabled and the device fails to obtain a valid IP address, it falls back to LLA (Link-Local Address).\n        If Persistent IP and DHCP are both enabled, the sensor starts with the attemp to used the specified persistent IP\n        and falls back to DHCP if Persistent IP fails."
@@ -260,8 +276,11 @@ define_struct_property!(
     OBDeviceSerialNumber,
     "@brief get/set serial number"
 );
-///This property is a struct, but we don't have a struct value type
-struct DeviceStaticIpConfigRecord;
+define_struct_property!(
+    DeviceStaticIpConfigRecord,
+    OBNetIpConfig,
+    "@brief Device network static ip config record\n @brief Using for get last static ip config, witch is record in device flash when user set static ip config\n @see OBNetIpConfig\n\n @attention read only"
+);
 define_struct_property!(
     DeviceTemperature,
     OBDeviceTemperature,
@@ -493,8 +512,7 @@ define_bool_property!(
     RestoreFactorySettings,
     "@brief Restore factory settings and factory parameters\n @attention This command can only be written, and the parameter value must be true. The command takes effect after restarting the device."
 );
-///This property is a struct, but we don't have a struct value type
-struct RgbCropRoi;
+define_struct_property!(RgbCropRoi, OBRegionOfInterest, "@brief RGB cropping ROI");
 define_bool_property!(
     RgbCustomCrop,
     "@brief Custom RGB cropping switch, 0 is off, 1 is on custom cropping, and the ROI cropping area is issued"
@@ -555,8 +573,11 @@ define_bool_property!(
     "@brief Enable send reset device time signal to other device. true: enable, false: disable"
 );
 define_int_property!(TimestampOffset, "@brief Timestamp adjustment");
-///This property is a struct, but we don't have a struct value type
-struct TofExposureThresholdControl;
+define_struct_property!(
+    TofExposureThresholdControl,
+    OBTofExposureThresholdControl,
+    "@brief TOF exposure threshold range"
+);
 define_int_property!(TofFilterRange, "@brief tof filter range configuration");
 define_int_property!(
     UsbPowerState,
@@ -566,3 +587,951 @@ define_bool_property!(
     Watchdog,
     "@brief Watchdog function switch, 0: Disable, 1: Enable"
 );
+/// Name, ID and value type of every bool, int and float property, sorted by name
+pub(crate) const PROPERTY_TABLE: &[(&str, OBPropertyID, OBPropertyType)] = &[
+    (
+        "AntiCollusionActivationStatus",
+        OBPropertyID::AntiCollusionActivationStatus,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "AutoCaptureEnable",
+        OBPropertyID::AutoCaptureEnable,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "AutoCaptureIntervalTime",
+        OBPropertyID::AutoCaptureIntervalTime,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "BootIntoRecoveryMode",
+        OBPropertyID::BootIntoRecoveryMode,
+        OBPropertyType::BoolProperty,
+    ),
+    ("Brt", OBPropertyID::Brt, OBPropertyType::BoolProperty),
+    (
+        "CaptureImageFrameNumber",
+        OBPropertyID::CaptureImageFrameNumber,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "CaptureImageNumberInterval",
+        OBPropertyID::CaptureImageNumberInterval,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "CaptureImageSignal",
+        OBPropertyID::CaptureImageSignal,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "CaptureImageTimeInterval",
+        OBPropertyID::CaptureImageTimeInterval,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "CaptureIntervalMode",
+        OBPropertyID::CaptureIntervalMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "CheckPpsSyncInSignal",
+        OBPropertyID::CheckPpsSyncInSignal,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ColorAeMaxExposure",
+        OBPropertyID::ColorAeMaxExposure,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorAeMaxGain",
+        OBPropertyID::ColorAeMaxGain,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorAntiFlicker",
+        OBPropertyID::ColorAntiFlicker,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ColorAutoExposure",
+        OBPropertyID::ColorAutoExposure,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ColorAutoExposurePriority",
+        OBPropertyID::ColorAutoExposurePriority,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorAutoWhiteBalance",
+        OBPropertyID::ColorAutoWhiteBalance,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ColorBacklightCompensation",
+        OBPropertyID::ColorBacklightCompensation,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorBrightness",
+        OBPropertyID::ColorBrightness,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorContrast",
+        OBPropertyID::ColorContrast,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorDenoisingLevel",
+        OBPropertyID::ColorDenoisingLevel,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorExposure",
+        OBPropertyID::ColorExposure,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorFlip",
+        OBPropertyID::ColorFlip,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ColorFocus",
+        OBPropertyID::ColorFocus,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorGain",
+        OBPropertyID::ColorGain,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorGamma",
+        OBPropertyID::ColorGamma,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorHdr",
+        OBPropertyID::ColorHdr,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ColorHue",
+        OBPropertyID::ColorHue,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorLeftFlip",
+        OBPropertyID::ColorLeftFlip,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ColorLeftMirror",
+        OBPropertyID::ColorLeftMirror,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ColorLeftRotate",
+        OBPropertyID::ColorLeftRotate,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorMaximalShutter",
+        OBPropertyID::ColorMaximalShutter,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorMirror",
+        OBPropertyID::ColorMirror,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ColorPowerLineFrequency",
+        OBPropertyID::ColorPowerLineFrequency,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorPresetPriority",
+        OBPropertyID::ColorPresetPriority,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorRightFlip",
+        OBPropertyID::ColorRightFlip,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ColorRightMirror",
+        OBPropertyID::ColorRightMirror,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ColorRightRotate",
+        OBPropertyID::ColorRightRotate,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorRoiBrightness",
+        OBPropertyID::ColorRoiBrightness,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorRoll",
+        OBPropertyID::ColorRoll,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorRotate",
+        OBPropertyID::ColorRotate,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorSaturation",
+        OBPropertyID::ColorSaturation,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorSharpness",
+        OBPropertyID::ColorSharpness,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorShutter",
+        OBPropertyID::ColorShutter,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ColorWhiteBalance",
+        OBPropertyID::ColorWhiteBalance,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ConfidenceFlip",
+        OBPropertyID::ConfidenceFlip,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ConfidenceMirror",
+        OBPropertyID::ConfidenceMirror,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ConfidenceRotate",
+        OBPropertyID::ConfidenceRotate,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "ConfidenceStreamFilter",
+        OBPropertyID::ConfidenceStreamFilter,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ConfidenceStreamFilterThreshold",
+        OBPropertyID::ConfidenceStreamFilterThreshold,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "CpuTemperatureCalibration",
+        OBPropertyID::CpuTemperatureCalibration,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "D2CPreprocess",
+        OBPropertyID::D2CPreprocess,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DcPowerState",
+        OBPropertyID::DcPowerState,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DebugEsgmConfidence",
+        OBPropertyID::DebugEsgmConfidence,
+        OBPropertyType::FloatProperty,
+    ),
+    (
+        "DepthAlignHardware",
+        OBPropertyID::DepthAlignHardware,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DepthAlignHardwareMode",
+        OBPropertyID::DepthAlignHardwareMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DepthAutoExposure",
+        OBPropertyID::DepthAutoExposure,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DepthAutoExposurePriority",
+        OBPropertyID::DepthAutoExposurePriority,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DepthCroppingMode",
+        OBPropertyID::DepthCroppingMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DepthExposure",
+        OBPropertyID::DepthExposure,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DepthFlip",
+        OBPropertyID::DepthFlip,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DepthGain",
+        OBPropertyID::DepthGain,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DepthHolefilter",
+        OBPropertyID::DepthHolefilter,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DepthIndustryMode",
+        OBPropertyID::DepthIndustryMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DepthMirror",
+        OBPropertyID::DepthMirror,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DepthNoiseRemovalFilter",
+        OBPropertyID::DepthNoiseRemovalFilter,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DepthNoiseRemovalFilterMaxDiff",
+        OBPropertyID::DepthNoiseRemovalFilterMaxDiff,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DepthNoiseRemovalFilterMaxSpeckleSize",
+        OBPropertyID::DepthNoiseRemovalFilterMaxSpeckleSize,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DepthPostfilter",
+        OBPropertyID::DepthPostfilter,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DepthPrecisionLevel",
+        OBPropertyID::DepthPrecisionLevel,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DepthRmFilter",
+        OBPropertyID::DepthRmFilter,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DepthRotate",
+        OBPropertyID::DepthRotate,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DepthUnitFlexibleAdjustment",
+        OBPropertyID::DepthUnitFlexibleAdjustment,
+        OBPropertyType::FloatProperty,
+    ),
+    (
+        "DepthWithConfidenceStreamEnable",
+        OBPropertyID::DepthWithConfidenceStreamEnable,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DeviceAeReference",
+        OBPropertyID::DeviceAeReference,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DeviceAeStrategy",
+        OBPropertyID::DeviceAeStrategy,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DeviceCommunicationType",
+        OBPropertyID::DeviceCommunicationType,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DeviceDevelopmentMode",
+        OBPropertyID::DeviceDevelopmentMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DeviceInRecoveryMode",
+        OBPropertyID::DeviceInRecoveryMode,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DeviceIpMode",
+        OBPropertyID::DeviceIpMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DeviceNetworkLla",
+        OBPropertyID::DeviceNetworkLla,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DeviceOfflineAfterIpConfigApply",
+        OBPropertyID::DeviceOfflineAfterIpConfigApply,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DevicePerformanceMode",
+        OBPropertyID::DevicePerformanceMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DeviceRebootDelay",
+        OBPropertyID::DeviceRebootDelay,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DeviceRepower",
+        OBPropertyID::DeviceRepower,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DeviceUsb2RepeatIdentify",
+        OBPropertyID::DeviceUsb2RepeatIdentify,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "DeviceWorkMode",
+        OBPropertyID::DeviceWorkMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DhcpAssignIpTimeout",
+        OBPropertyID::DhcpAssignIpTimeout,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DispSearchOffset",
+        OBPropertyID::DispSearchOffset,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DispSearchRangeMode",
+        OBPropertyID::DispSearchRangeMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "DisparityToDepth",
+        OBPropertyID::DisparityToDepth,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "ExternalSignalReset",
+        OBPropertyID::ExternalSignalReset,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "FanWorkMode",
+        OBPropertyID::FanWorkMode,
+        OBPropertyType::IntProperty,
+    ),
+    ("Flood", OBPropertyID::Flood, OBPropertyType::BoolProperty),
+    (
+        "FloodLevel",
+        OBPropertyID::FloodLevel,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "FrameInterleaveConfigIndex",
+        OBPropertyID::FrameInterleaveConfigIndex,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "FrameInterleaveEnable",
+        OBPropertyID::FrameInterleaveEnable,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "FrameInterleaveLaserPatternSyncDelay",
+        OBPropertyID::FrameInterleaveLaserPatternSyncDelay,
+        OBPropertyType::IntProperty,
+    ),
+    ("Gpm", OBPropertyID::Gpm, OBPropertyType::BoolProperty),
+    (
+        "HardwareDistortionSwitch",
+        OBPropertyID::HardwareDistortionSwitch,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "HdrMerge",
+        OBPropertyID::HdrMerge,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "Heartbeat",
+        OBPropertyID::Heartbeat,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "HwNoiseRemoveFilterEnable",
+        OBPropertyID::HwNoiseRemoveFilterEnable,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "HwNoiseRemoveFilterThreshold",
+        OBPropertyID::HwNoiseRemoveFilterThreshold,
+        OBPropertyType::FloatProperty,
+    ),
+    (
+        "IndicatorLight",
+        OBPropertyID::IndicatorLight,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "IntraCameraSyncReference",
+        OBPropertyID::IntraCameraSyncReference,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "IrAeMaxExposure",
+        OBPropertyID::IrAeMaxExposure,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "IrAutoExposure",
+        OBPropertyID::IrAutoExposure,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "IrBrightness",
+        OBPropertyID::IrBrightness,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "IrChannelDataSource",
+        OBPropertyID::IrChannelDataSource,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "IrExposure",
+        OBPropertyID::IrExposure,
+        OBPropertyType::IntProperty,
+    ),
+    ("IrFlip", OBPropertyID::IrFlip, OBPropertyType::BoolProperty),
+    ("IrGain", OBPropertyID::IrGain, OBPropertyType::IntProperty),
+    (
+        "IrLongExposure",
+        OBPropertyID::IrLongExposure,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "IrMirror",
+        OBPropertyID::IrMirror,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "IrRectify",
+        OBPropertyID::IrRectify,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "IrRightFlip",
+        OBPropertyID::IrRightFlip,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "IrRightMirror",
+        OBPropertyID::IrRightMirror,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "IrRightRotate",
+        OBPropertyID::IrRightRotate,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "IrRotate",
+        OBPropertyID::IrRotate,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "IrShortExposure",
+        OBPropertyID::IrShortExposure,
+        OBPropertyType::BoolProperty,
+    ),
+    ("Laser", OBPropertyID::Laser, OBPropertyType::BoolProperty),
+    (
+        "LaserAlwaysOn",
+        OBPropertyID::LaserAlwaysOn,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "LaserControl",
+        OBPropertyID::LaserControl,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LaserCurrent",
+        OBPropertyID::LaserCurrent,
+        OBPropertyType::FloatProperty,
+    ),
+    (
+        "LaserHighTemperatureProtect",
+        OBPropertyID::LaserHighTemperatureProtect,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "LaserMode",
+        OBPropertyID::LaserMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LaserOnOffPattern",
+        OBPropertyID::LaserOnOffPattern,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LaserOvercurrentProtectionStatus",
+        OBPropertyID::LaserOvercurrentProtectionStatus,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "LaserPowerActualLevel",
+        OBPropertyID::LaserPowerActualLevel,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LaserPowerLevelControl",
+        OBPropertyID::LaserPowerLevelControl,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LaserPulseWidth",
+        OBPropertyID::LaserPulseWidth,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LaserPulseWidthProtectionStatus",
+        OBPropertyID::LaserPulseWidthProtectionStatus,
+        OBPropertyType::BoolProperty,
+    ),
+    ("Ldp", OBPropertyID::Ldp, OBPropertyType::BoolProperty),
+    (
+        "LdpMeasureDistance",
+        OBPropertyID::LdpMeasureDistance,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LdpStatus",
+        OBPropertyID::LdpStatus,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "LidarApdTemperature",
+        OBPropertyID::LidarApdTemperature,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LidarApplyConfigs",
+        OBPropertyID::LidarApplyConfigs,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LidarMcuTemperature",
+        OBPropertyID::LidarMcuTemperature,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LidarMemsFovSize",
+        OBPropertyID::LidarMemsFovSize,
+        OBPropertyType::FloatProperty,
+    ),
+    (
+        "LidarMemsFrenquency",
+        OBPropertyID::LidarMemsFrenquency,
+        OBPropertyType::FloatProperty,
+    ),
+    (
+        "LidarMotorSpinSpeed",
+        OBPropertyID::LidarMotorSpinSpeed,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LidarPort",
+        OBPropertyID::LidarPort,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LidarRepetitiveScanMode",
+        OBPropertyID::LidarRepetitiveScanMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LidarSpecificMode",
+        OBPropertyID::LidarSpecificMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LidarTailFilterLevel",
+        OBPropertyID::LidarTailFilterLevel,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LidarWarningInfo",
+        OBPropertyID::LidarWarningInfo,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LidarWorkMode",
+        OBPropertyID::LidarWorkMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "LowExposureLaserControl",
+        OBPropertyID::LowExposureLaserControl,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "MaxDepth",
+        OBPropertyID::MaxDepth,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "MinDepth",
+        OBPropertyID::MinDepth,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "NetworkBandwidthType",
+        OBPropertyID::NetworkBandwidthType,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "OnChipCalibrationEnable",
+        OBPropertyID::OnChipCalibrationEnable,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "OnChipCalibrationHealthCheck",
+        OBPropertyID::OnChipCalibrationHealthCheck,
+        OBPropertyType::FloatProperty,
+    ),
+    (
+        "PtpClockSyncEnable",
+        OBPropertyID::PtpClockSyncEnable,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "Rectify2",
+        OBPropertyID::Rectify2,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "RestoreFactorySettings",
+        OBPropertyID::RestoreFactorySettings,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "RgbCustomCrop",
+        OBPropertyID::RgbCustomCrop,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "SdkAccelFrameTransformed",
+        OBPropertyID::SdkAccelFrameTransformed,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "SdkDepthFrameUnpack",
+        OBPropertyID::SdkDepthFrameUnpack,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "SdkDisparityToDepth",
+        OBPropertyID::SdkDisparityToDepth,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "SdkGyroFrameTransformed",
+        OBPropertyID::SdkGyroFrameTransformed,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "SdkIrFrameUnpack",
+        OBPropertyID::SdkIrFrameUnpack,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "SdkIrLeftFrameUnpack",
+        OBPropertyID::SdkIrLeftFrameUnpack,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "SdkIrRightFrameUnpack",
+        OBPropertyID::SdkIrRightFrameUnpack,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "SkipFrame",
+        OBPropertyID::SkipFrame,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "SlaveDeviceSyncStatus",
+        OBPropertyID::SlaveDeviceSyncStatus,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "SwitchIrMode",
+        OBPropertyID::SwitchIrMode,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "SyncSignalTriggerOut",
+        OBPropertyID::SyncSignalTriggerOut,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "TemperatureCompensation",
+        OBPropertyID::TemperatureCompensation,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "TimerResetDelayUs",
+        OBPropertyID::TimerResetDelayUs,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "TimerResetEnable",
+        OBPropertyID::TimerResetEnable,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "TimerResetSignal",
+        OBPropertyID::TimerResetSignal,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "TimerResetTriggerOutEnable",
+        OBPropertyID::TimerResetTriggerOutEnable,
+        OBPropertyType::BoolProperty,
+    ),
+    (
+        "TimestampOffset",
+        OBPropertyID::TimestampOffset,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "TofFilterRange",
+        OBPropertyID::TofFilterRange,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "UsbPowerState",
+        OBPropertyID::UsbPowerState,
+        OBPropertyType::IntProperty,
+    ),
+    (
+        "Watchdog",
+        OBPropertyID::Watchdog,
+        OBPropertyType::BoolProperty,
+    ),
+];
+/// Name, ID and value size of every struct property with a known value type, sorted by name
+pub(crate) const STRUCT_PROPERTY_TABLE: &[(&str, OBPropertyID, usize)] = &[
+    (
+        "AsicSerialNumber",
+        OBPropertyID::AsicSerialNumber,
+        size_of::<OBDeviceSerialNumber>(),
+    ),
+    (
+        "BaselineCalibrationParam",
+        OBPropertyID::BaselineCalibrationParam,
+        size_of::<OBBaselineCalibrationParam>(),
+    ),
+    (
+        "ColorAeRoi",
+        OBPropertyID::ColorAeRoi,
+        size_of::<OBRegionOfInterest>(),
+    ),
+    (
+        "DepthAeRoi",
+        OBPropertyID::DepthAeRoi,
+        size_of::<OBRegionOfInterest>(),
+    ),
+    (
+        "DepthHdrConfig",
+        OBPropertyID::DepthHdrConfig,
+        size_of::<OBHdrConfig>(),
+    ),
+    (
+        "DeviceIpAddrConfig",
+        OBPropertyID::DeviceIpAddrConfig,
+        size_of::<OBNetIpConfig>(),
+    ),
+    (
+        "DeviceIpAddrConfigV2",
+        OBPropertyID::DeviceIpAddrConfigV2,
+        size_of::<OBNetIpConfigV2>(),
+    ),
+    (
+        "DeviceSerialNumber",
+        OBPropertyID::DeviceSerialNumber,
+        size_of::<OBDeviceSerialNumber>(),
+    ),
+    (
+        "DeviceStaticIpConfigRecord",
+        OBPropertyID::DeviceStaticIpConfigRecord,
+        size_of::<OBNetIpConfig>(),
+    ),
+    (
+        "DeviceTemperature",
+        OBPropertyID::DeviceTemperature,
+        size_of::<OBDeviceTemperature>(),
+    ),
+    (
+        "DeviceTime",
+        OBPropertyID::DeviceTime,
+        size_of::<OBDeviceTime>(),
+    ),
+    (
+        "DispOffsetConfig",
+        OBPropertyID::DispOffsetConfig,
+        size_of::<OBDispOffsetConfig>(),
+    ),
+    (
+        "MultiDeviceSyncConfig",
+        OBPropertyID::MultiDeviceSyncConfig,
+        size_of::<OBMultiDeviceSyncConfig>(),
+    ),
+    (
+        "PresetResolutionConfig",
+        OBPropertyID::PresetResolutionConfig,
+        size_of::<OBPresetResolutionConfig>(),
+    ),
+    (
+        "RgbCropRoi",
+        OBPropertyID::RgbCropRoi,
+        size_of::<OBRegionOfInterest>(),
+    ),
+    (
+        "TofExposureThresholdControl",
+        OBPropertyID::TofExposureThresholdControl,
+        size_of::<OBTofExposureThresholdControl>(),
+    ),
+];
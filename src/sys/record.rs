@@ -0,0 +1,111 @@
+//! Recording and playback of device streams
+use std::ffi::CStr;
+
+use super::device::OBDevice;
+use super::orb::OBPlaybackStatus;
+use super::{OBError, call_ob_function, drop_ob_object, impl_ob_method, orb};
+
+/// Raw playback status callback type from the C API.
+pub(crate) type OBPlaybackStatusCallback = orb::ob_playback_status_changed_callback;
+
+/// Recorder that writes the streams of a device to a file
+pub struct OBRecordDevice {
+    inner: *mut orb::ob_record_device,
+}
+
+drop_ob_object!(OBRecordDevice, ob_delete_record_device);
+
+impl OBRecordDevice {
+    /// Create a recording device for the specified device, recording to the specified file
+    pub fn new(
+        device: &OBDevice,
+        file_path: &CStr,
+        compression_enabled: bool,
+    ) -> Result<Self, OBError> {
+        let recorder = call_ob_function!(
+            orb::ob_create_record_device,
+            device.inner(),
+            file_path.as_ptr(),
+            compression_enabled
+        )?;
+        Ok(OBRecordDevice { inner: recorder })
+    }
+
+    impl_ob_method!(
+        /// Pause recording
+        pause => (),
+        orb::ob_record_device_pause,
+    );
+
+    impl_ob_method!(
+        /// Resume recording
+        resume => (),
+        orb::ob_record_device_resume,
+    );
+}
+
+/// Create a playback device for the specified file
+pub fn create_playback_device(file_path: &CStr) -> Result<OBDevice, OBError> {
+    let device = call_ob_function!(orb::ob_create_playback_device, file_path.as_ptr())?;
+    Ok(OBDevice::new(device))
+}
+
+/// Playback specific operations.
+/// Only valid for devices created with [`create_playback_device`].
+impl OBDevice {
+    /// Pause playback
+    pub fn playback_pause(&self) -> Result<(), OBError> {
+        call_ob_function!(orb::ob_playback_device_pause, self.inner())
+    }
+
+    /// Resume playback
+    pub fn playback_resume(&self) -> Result<(), OBError> {
+        call_ob_function!(orb::ob_playback_device_resume, self.inner())
+    }
+
+    /// Set the playback to a specified time point (in milliseconds)
+    pub fn playback_seek(&self, timestamp_ms: u64) -> Result<(), OBError> {
+        call_ob_function!(orb::ob_playback_device_seek, self.inner(), timestamp_ms)
+    }
+
+    /// Set the playback rate
+    pub fn playback_set_rate(&self, rate: f32) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_playback_device_set_playback_rate,
+            self.inner(),
+            rate
+        )
+    }
+
+    /// Get the current playback status
+    pub fn playback_get_status(&self) -> Result<OBPlaybackStatus, OBError> {
+        call_ob_function!(
+            orb::ob_playback_device_get_current_playback_status,
+            self.inner()
+        )
+    }
+
+    /// Set a callback to receive playback status updates
+    pub fn playback_set_status_callback(
+        &self,
+        callback: OBPlaybackStatusCallback,
+        user_data: *mut std::ffi::c_void,
+    ) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_playback_device_set_playback_status_changed_callback,
+            self.inner(),
+            callback,
+            user_data
+        )
+    }
+
+    /// Get the current playback position (in milliseconds)
+    pub fn playback_get_position(&self) -> Result<u64, OBError> {
+        call_ob_function!(orb::ob_playback_device_get_position, self.inner())
+    }
+
+    /// Get the duration of the played data (in milliseconds)
+    pub fn playback_get_duration(&self) -> Result<u64, OBError> {
+        call_ob_function!(orb::ob_playback_device_get_duration, self.inner())
+    }
+}
@@ -0,0 +1,124 @@
+//! Sensor management and streaming
+use super::filter::OBFilterList;
+use super::frame::OBFrame;
+use super::orb::OBSensorType;
+use super::stream::{OBStreamProfile, OBStreamProfileList};
+use super::{OBError, call_ob_function, drop_ob_object, impl_ob_method, orb};
+
+/// Raw frame callback type from the C API.
+pub(crate) type OBFrameCallback = orb::ob_frame_callback;
+
+pub(crate) unsafe extern "C" fn frame_trampoline(
+    frame: *mut orb::ob_frame,
+    user_data: *mut std::ffi::c_void,
+) {
+    let callback = unsafe { &mut *(user_data as *mut Box<dyn FnMut(OBFrame) + Send>) };
+    callback(OBFrame::new(frame));
+}
+
+/// A single sensor of a device (e.g. depth, color or IR)
+pub struct OBSensor {
+    inner: *mut orb::ob_sensor,
+}
+
+drop_ob_object!(OBSensor, ob_delete_sensor);
+
+impl OBSensor {
+    pub(crate) fn new(inner: *mut orb::ob_sensor) -> Self {
+        OBSensor { inner }
+    }
+
+    impl_ob_method!(
+        /// Get the type of the sensor
+        get_type => OBSensorType,
+        orb::ob_sensor_get_type,
+    );
+
+    /// Get a list of all supported stream profiles
+    pub fn get_stream_profile_list(&self) -> Result<OBStreamProfileList, OBError> {
+        let list = call_ob_function!(orb::ob_sensor_get_stream_profile_list, self.inner)?;
+        Ok(OBStreamProfileList::new(list))
+    }
+
+    /// Open the sensor with the given profile, invoking the callback for each frame
+    pub fn start(
+        &self,
+        profile: &OBStreamProfile,
+        callback: OBFrameCallback,
+        user_data: *mut std::ffi::c_void,
+    ) -> Result<(), OBError> {
+        call_ob_function!(
+            orb::ob_sensor_start,
+            self.inner,
+            profile.inner(),
+            callback,
+            user_data
+        )
+    }
+
+    impl_ob_method!(
+        /// Stop the sensor stream
+        stop => (),
+        orb::ob_sensor_stop,
+    );
+
+    /// Create the list of post-processing filters recommended for the sensor
+    pub fn create_recommended_filter_list(&self) -> Result<OBFilterList, OBError> {
+        let list = call_ob_function!(orb::ob_sensor_create_recommended_filter_list, self.inner)?;
+        Ok(OBFilterList::new(list))
+    }
+
+    /// Switch the stream profile of a running sensor
+    pub fn switch_profile(&self, profile: &OBStreamProfile) -> Result<(), OBError> {
+        call_ob_function!(orb::ob_sensor_switch_profile, self.inner, profile.inner())
+    }
+}
+
+/// List of sensors
+pub struct OBSensorList {
+    inner: *mut orb::ob_sensor_list,
+}
+
+drop_ob_object!(OBSensorList, ob_delete_sensor_list);
+
+impl OBSensorList {
+    pub(crate) fn new(inner: *mut orb::ob_sensor_list) -> Self {
+        OBSensorList { inner }
+    }
+
+    impl_ob_method!(
+        /// Get the number of sensors in the list
+        get_count => u32,
+        orb::ob_sensor_list_get_count,
+    );
+
+    impl_ob_method!(
+        /// Get the type of the sensor at the specified index
+        get_sensor_type => OBSensorType,
+        orb::ob_sensor_list_get_sensor_type,
+        index: u32,
+    );
+
+    /// Get the sensor at the specified index
+    pub fn get_sensor(&self, index: u32) -> Result<OBSensor, OBError> {
+        let sensor = call_ob_function!(orb::ob_sensor_list_get_sensor, self.inner, index)?;
+        Ok(OBSensor::new(sensor))
+    }
+
+    /// Get the sensor of the specified type, if present
+    pub fn get_sensor_by_type(
+        &self,
+        sensor_type: OBSensorType,
+    ) -> Result<Option<OBSensor>, OBError> {
+        let sensor = call_ob_function!(
+            orb::ob_sensor_list_get_sensor_by_type,
+            self.inner,
+            sensor_type
+        )?;
+        Ok(if sensor.is_null() {
+            None
+        } else {
+            Some(OBSensor::new(sensor))
+        })
+    }
+}
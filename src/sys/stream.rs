@@ -1,6 +1,10 @@
 //! Stream profiles and related operations
 use super::{OBError, call_ob_function, drop_ob_object, impl_ob_method, orb};
-use crate::sys::orb::{OBCameraDistortion, OBCameraIntrinsic, OBD2CTransform, OBFormat};
+use crate::sys::orb::{
+    OBAccelFullScaleRange, OBAccelIntrinsic, OBAccelSampleRate, OBCameraDistortion,
+    OBCameraIntrinsic, OBD2CTransform, OBFormat, OBGyroFullScaleRange, OBGyroIntrinsic,
+    OBGyroSampleRate, OBStreamType,
+};
 
 /// Stream profile
 pub struct OBStreamProfile {
@@ -40,6 +44,12 @@ impl OBStreamProfile {
         Ok(transform)
     }
 
+    impl_ob_method!(
+        /// Get stream profile type
+        get_type => OBStreamType,
+        orb::ob_stream_profile_get_type,
+    );
+
     impl_ob_method!(
         /// Get stream profile format
         get_format => OBFormat,
@@ -66,6 +76,48 @@ impl OBStreamProfile {
         get_video_height => u32,
         orb::ob_video_stream_profile_get_height,
     );
+
+    impl_ob_method!(
+        /// Get the full-scale range of the accelerometer stream.
+        /// Returns error if the profile is not an accelerometer stream profile.
+        get_accel_full_scale_range => OBAccelFullScaleRange,
+        orb::ob_accel_stream_profile_get_full_scale_range,
+    );
+
+    impl_ob_method!(
+        /// Get the sample rate of the accelerometer stream.
+        /// Returns error if the profile is not an accelerometer stream profile.
+        get_accel_sample_rate => OBAccelSampleRate,
+        orb::ob_accel_stream_profile_get_sample_rate,
+    );
+
+    impl_ob_method!(
+        /// Get the intrinsic of the accelerometer stream.
+        /// Returns error if the profile is not an accelerometer stream profile.
+        get_accel_intrinsic => OBAccelIntrinsic,
+        orb::ob_accel_stream_profile_get_intrinsic,
+    );
+
+    impl_ob_method!(
+        /// Get the full-scale range of the gyroscope stream.
+        /// Returns error if the profile is not a gyroscope stream profile.
+        get_gyro_full_scale_range => OBGyroFullScaleRange,
+        orb::ob_gyro_stream_profile_get_full_scale_range,
+    );
+
+    impl_ob_method!(
+        /// Get the sample rate of the gyroscope stream.
+        /// Returns error if the profile is not a gyroscope stream profile.
+        get_gyro_sample_rate => OBGyroSampleRate,
+        orb::ob_gyro_stream_profile_get_sample_rate,
+    );
+
+    impl_ob_method!(
+        /// Get the intrinsic of the gyroscope stream.
+        /// Returns error if the profile is not a gyroscope stream profile.
+        get_gyro_intrinsic => OBGyroIntrinsic,
+        orb::ob_gyro_stream_get_intrinsic,
+    );
 }
 
 /// List of video stream profiles
@@ -114,4 +166,34 @@ impl OBStreamProfileList {
         )?;
         Ok(OBStreamProfile::new(profile))
     }
+
+    /// Match the accelerometer stream profile through the passed parameters. If there are multiple matches, the first one in the list will be returned by default.
+    pub fn get_accel_stream_profile(
+        &self,
+        full_scale_range: OBAccelFullScaleRange,
+        sample_rate: OBAccelSampleRate,
+    ) -> Result<OBStreamProfile, OBError> {
+        let profile = call_ob_function!(
+            orb::ob_stream_profile_list_get_accel_stream_profile,
+            self.inner,
+            full_scale_range,
+            sample_rate
+        )?;
+        Ok(OBStreamProfile::new(profile))
+    }
+
+    /// Match the gyroscope stream profile through the passed parameters. If there are multiple matches, the first one in the list will be returned by default.
+    pub fn get_gyro_stream_profile(
+        &self,
+        full_scale_range: OBGyroFullScaleRange,
+        sample_rate: OBGyroSampleRate,
+    ) -> Result<OBStreamProfile, OBError> {
+        let profile = call_ob_function!(
+            orb::ob_stream_profile_list_get_gyro_stream_profile,
+            self.inner,
+            full_scale_range,
+            sample_rate
+        )?;
+        Ok(OBStreamProfile::new(profile))
+    }
 }